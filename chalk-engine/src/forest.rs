@@ -39,14 +39,14 @@ impl<I: Interner> Forest<I> {
     /// invocations. Invoking `next` fewer times is preferable =)
     pub fn iter_answers<'f>(
         &'f mut self,
-        context: &'f SlgContextOps<'f, I>,
+        context: &SlgContextOps<'f, I>,
         goal: &UCanonical<InEnvironment<Goal<I>>>,
     ) -> impl AnswerStream<I> + 'f {
         let table = self.get_or_create_table_for_ucanonical_goal(context, goal.clone());
         let answer = AnswerIndex::ZERO;
         ForestSolver {
             forest: self,
-            context,
+            context: context.clone(),
             table,
             answer,
         }
@@ -55,7 +55,7 @@ impl<I: Interner> Forest<I> {
 
 struct ForestSolver<'me, I: Interner> {
     forest: &'me mut Forest<I>,
-    context: &'me SlgContextOps<'me, I>,
+    context: SlgContextOps<'me, I>,
     table: TableIndex,
     answer: AnswerIndex,
 }
@@ -68,7 +68,7 @@ impl<'me, I: Interner> AnswerStream<I> for ForestSolver<'me, I> {
         loop {
             match self
                 .forest
-                .root_answer(self.context, self.table, self.answer)
+                .root_answer(&self.context, self.table, self.answer)
             {
                 Ok(answer) => {
                     debug!(answer = ?(&answer));
@@ -15,7 +15,9 @@ use chalk_ir::{
     AnswerSubst, Canonical, ConstrainedSubst, Constraints, FallibleOrFloundered, Floundered, Goal,
     GoalData, InEnvironment, NoSolution, ProgramClause, Substitution, UCanonical, UniverseMap,
 };
-use chalk_solve::clauses::program_clauses_that_could_match;
+use chalk_solve::clauses::{
+    dedup_program_clauses, drop_env_only_clauses, program_clauses_that_could_match,
+};
 use chalk_solve::coinductive_goal::IsCoinductive;
 use chalk_solve::infer::ucanonicalize::UCanonicalized;
 use chalk_solve::infer::InferenceTable;
@@ -273,8 +275,10 @@ impl<I: Interner> Forest<I> {
                                 .cloned()
                                 .filter(could_match),
                         );
+                        dedup_program_clauses(&mut clauses);
 
                         let InEnvironment { environment, goal } = goal;
+                        drop_env_only_clauses(db, &environment, &goal, &mut clauses);
 
                         for clause in clauses {
                             info!("program clause = {:#?}", clause);
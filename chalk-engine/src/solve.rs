@@ -2,26 +2,110 @@ use crate::context::{AnswerResult, AnswerStream};
 use crate::forest::Forest;
 use crate::slg::aggregate::AggregateOps;
 use crate::slg::SlgContextOps;
+use crate::CompleteAnswer;
 use chalk_ir::interner::Interner;
 use chalk_ir::{Canonical, ConstrainedSubst, Goal, InEnvironment, UCanonical};
+use chalk_solve::solve::{simplify_canonical_goal, Cache};
 use chalk_solve::{RustIrDatabase, Solution, Solver, SubstitutionResult};
 
 use std::fmt;
+use std::iter;
+
+/// A cache of the solutions of top-level goals that can be shared between
+/// `SLGSolver`s.
+///
+/// The solutions are only valid for the program they were computed with, and
+/// the cache has no way to tell programs apart: like the rest of a solver's
+/// cached state (see `Solver::solve`), a cache must only be shared between
+/// solvers for the same program.
+pub type SolutionCache<I> = Cache<SolutionCacheKey<I>, Option<Solution<I>>>;
+
+/// The key of a `SolutionCache`: a top-level goal along with the limits of
+/// the solver that solved it, since those limits can change the solution.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SolutionCacheKey<I: Interner> {
+    pub goal: UCanonical<InEnvironment<Goal<I>>>,
+    pub max_size: usize,
+    pub expected_answers: Option<usize>,
+}
 
 pub struct SLGSolver<I: Interner> {
     pub(crate) forest: Forest<I>,
     pub(crate) max_size: usize,
     pub(crate) expected_answers: Option<usize>,
+
+    /// Solutions of top-level goals, which can be shared with other solvers
+    /// for the same program so that they don't redo work for the same goal.
+    /// A solution found by `solve_limited` is not cached, since it may have
+    /// been cut short. Only goals in an empty environment are cached, so that
+    /// a cached solution doesn't depend on the assumptions of the query it
+    /// came from.
+    cache: Option<SolutionCache<I>>,
 }
 
 impl<I: Interner> SLGSolver<I> {
-    pub fn new(max_size: usize, expected_answers: Option<usize>) -> Self {
+    pub fn new(
+        max_size: usize,
+        expected_answers: Option<usize>,
+        cache: Option<SolutionCache<I>>,
+    ) -> Self {
         Self {
             forest: Forest::new(),
             max_size,
             expected_answers,
+            cache,
         }
     }
+
+    /// Returns the answers to `goal` one at a time, as `solve_multiple` would
+    /// pass them to its callback. Each answer is only computed when the
+    /// iterator is advanced, so callers can stop after as many answers as
+    /// they need, even for goals with infinitely many of them. The iterator
+    /// ends after the goal flounders.
+    pub fn answers<'a>(
+        &'a mut self,
+        program: &'a dyn RustIrDatabase<I>,
+        goal: &UCanonical<InEnvironment<Goal<I>>>,
+    ) -> impl Iterator<Item = SubstitutionResult<Canonical<ConstrainedSubst<I>>>> + 'a {
+        let interner = program.interner();
+        let goal = simplify_canonical_goal(interner, goal);
+        let ops = SlgContextOps::new(program, self.max_size, self.expected_answers);
+        let mut answers = Some(self.forest.iter_answers(&ops, &goal));
+        iter::from_fn(move || loop {
+            match answers.as_mut()?.next_answer(|| true) {
+                AnswerResult::Answer(answer) => return Some(substitution_result(interner, answer)),
+                AnswerResult::Floundered => {
+                    answers = None;
+                    return Some(SubstitutionResult::Floundered);
+                }
+                AnswerResult::NoMoreSolutions => {
+                    answers = None;
+                    return None;
+                }
+                AnswerResult::QuantumExceeded => continue,
+            }
+        })
+    }
+
+    /// The cache to use for `goal` along with its key, if it can be cached
+    /// at all.
+    fn cache_for(
+        &self,
+        program: &dyn RustIrDatabase<I>,
+        goal: &UCanonical<InEnvironment<Goal<I>>>,
+    ) -> Option<(SolutionCache<I>, SolutionCacheKey<I>)> {
+        let environment = &goal.canonical.value.environment;
+        let cache = self
+            .cache
+            .as_ref()
+            .filter(|_| environment.clauses.is_empty(program.interner()))?;
+        let key = SolutionCacheKey {
+            goal: goal.clone(),
+            max_size: self.max_size,
+            expected_answers: self.expected_answers,
+        };
+        Some((cache.clone(), key))
+    }
 }
 
 impl<I: Interner> fmt::Debug for SLGSolver<I> {
@@ -36,8 +120,17 @@ impl<I: Interner> Solver<I> for SLGSolver<I> {
         program: &dyn RustIrDatabase<I>,
         goal: &UCanonical<InEnvironment<Goal<I>>>,
     ) -> Option<Solution<I>> {
+        let goal = &simplify_canonical_goal(program.interner(), goal);
+        let cache = self.cache_for(program, goal);
+        if let Some(solution) = cache.as_ref().and_then(|(cache, key)| cache.get(key)) {
+            return solution;
+        }
         let ops = SlgContextOps::new(program, self.max_size, self.expected_answers);
-        ops.make_solution(goal, self.forest.iter_answers(&ops, goal), || true)
+        let solution = ops.make_solution(goal, self.forest.iter_answers(&ops, goal), || true);
+        if let Some((cache, key)) = cache {
+            cache.insert(key, solution.clone());
+        }
+        solution
     }
 
     fn solve_limited(
@@ -46,6 +139,13 @@ impl<I: Interner> Solver<I> for SLGSolver<I> {
         goal: &UCanonical<InEnvironment<Goal<I>>>,
         should_continue: &dyn std::ops::Fn() -> bool,
     ) -> Option<Solution<I>> {
+        let goal = &simplify_canonical_goal(program.interner(), goal);
+        if let Some(solution) = self
+            .cache_for(program, goal)
+            .and_then(|(cache, key)| cache.get(&key))
+        {
+            return solution;
+        }
         let ops = SlgContextOps::new(program, self.max_size, self.expected_answers);
         ops.make_solution(goal, self.forest.iter_answers(&ops, goal), should_continue)
     }
@@ -56,23 +156,13 @@ impl<I: Interner> Solver<I> for SLGSolver<I> {
         goal: &UCanonical<InEnvironment<Goal<I>>>,
         f: &mut dyn FnMut(SubstitutionResult<Canonical<ConstrainedSubst<I>>>, bool) -> bool,
     ) -> bool {
+        let goal = simplify_canonical_goal(program.interner(), goal);
         let ops = SlgContextOps::new(program, self.max_size, self.expected_answers);
-        let mut answers = self.forest.iter_answers(&ops, goal);
+        let mut answers = self.forest.iter_answers(&ops, &goal);
         loop {
             let subst = match answers.next_answer(|| true) {
                 AnswerResult::Answer(answer) => {
-                    if !answer.ambiguous {
-                        SubstitutionResult::Definite(answer.subst)
-                    } else if answer
-                        .subst
-                        .value
-                        .subst
-                        .is_identity_subst(ops.program().interner())
-                    {
-                        SubstitutionResult::Floundered
-                    } else {
-                        SubstitutionResult::Ambiguous(answer.subst)
-                    }
+                    substitution_result(ops.program().interner(), answer)
                 }
                 AnswerResult::Floundered => SubstitutionResult::Floundered,
                 AnswerResult::NoMoreSolutions => {
@@ -87,3 +177,19 @@ impl<I: Interner> Solver<I> for SLGSolver<I> {
         }
     }
 }
+
+/// Converts `answer` into the form returned by `solve_multiple`: an
+/// ambiguous answer that doesn't constrain any variables is reported as
+/// floundering.
+fn substitution_result<I: Interner>(
+    interner: I,
+    answer: CompleteAnswer<I>,
+) -> SubstitutionResult<Canonical<ConstrainedSubst<I>>> {
+    if !answer.ambiguous {
+        SubstitutionResult::Definite(answer.subst)
+    } else if answer.subst.value.subst.is_identity_subst(interner) {
+        SubstitutionResult::Floundered
+    } else {
+        SubstitutionResult::Ambiguous(answer.subst)
+    }
+}
@@ -7,18 +7,21 @@ use crate::{
     tls, SolverChoice,
 };
 use chalk_ir::{
-    AdtId, AssocTypeId, Binders, Canonical, CanonicalVarKinds, ClosureId, ConstrainedSubst,
-    Environment, FnDefId, GeneratorId, GenericArg, Goal, ImplId, InEnvironment, OpaqueTyId,
-    ProgramClause, ProgramClauses, Substitution, TraitId, Ty, TyKind, UCanonical,
-    UnificationDatabase, Variances,
+    AdtId, AssocConstId, AssocTypeId, Binders, Canonical, CanonicalVarKinds, ClosureId,
+    ConstrainedSubst, Environment, Floundered, FnDefId, GeneratorId, GenericArg, Goal, GoalData,
+    ImplId, InEnvironment, OpaqueTyId, ProgramClause, ProgramClauses, Substitution, TraitId, Ty,
+    TyKind, UCanonical, UnificationDatabase, Variances,
 };
+use chalk_solve::clauses::cycles::CycleError;
 use chalk_solve::rust_ir::{
-    AdtDatum, AdtRepr, AssociatedTyDatum, AssociatedTyValue, AssociatedTyValueId, ClosureKind,
-    FnDefDatum, FnDefInputsAndOutputDatum, GeneratorDatum, GeneratorWitnessDatum, ImplDatum,
-    OpaqueTyDatum, TraitDatum, WellKnownTrait,
+    AdtDatum, AdtRepr, AssociatedConstDatum, AssociatedTyDatum, AssociatedTyValue,
+    AssociatedTyValueId, ClosureKind, DefaultMethodDatum, FnDefDatum, FnDefInputsAndOutputDatum,
+    GeneratorDatum, GeneratorWitnessDatum, ImplDatum, OpaqueTyDatum, TraitDatum, WellKnownTrait,
 };
+use chalk_solve::wf::{WfError, WfSolver};
 use chalk_solve::{RustIrDatabase, Solution, SubstitutionResult};
 use salsa::Database;
+use std::error::Error;
 use std::fmt;
 use std::sync::Arc;
 
@@ -48,6 +51,65 @@ impl ChalkDatabase {
         Ok(lower_goal(&*chalk_parse::parse_goal(text)?, &*program)?)
     }
 
+    /// Returns the program clauses that the solvers consider for `goal`,
+    /// which must be a domain goal once peeled. Clauses are in the order
+    /// they would be tried: custom clauses, then those derived from the
+    /// program, then those from the environment.
+    pub fn program_clauses_for_goal(
+        &self,
+        goal: &UCanonical<InEnvironment<Goal<ChalkIr>>>,
+    ) -> Result<Vec<ProgramClause<ChalkIr>>, ChalkError> {
+        let interner = self.interner();
+        let domain_goal = match goal.canonical.value.goal.data(interner) {
+            GoalData::DomainGoal(domain_goal) => domain_goal.clone(),
+            _ => return Err(Box::<dyn Error>::from("not a domain goal").into()),
+        };
+        let goal = UCanonical {
+            canonical: Canonical {
+                binders: goal.canonical.binders.clone(),
+                value: InEnvironment::new(&goal.canonical.value.environment, domain_goal),
+            },
+            universes: goal.universes,
+        };
+        chalk_solve::clauses::program_clauses_for_goal(self, &goal)
+            .map_err(|Floundered| Box::<dyn Error>::from("floundered").into())
+    }
+
+    /// Returns the obligations of `impl_id`'s well-formedness check that
+    /// cannot be proven; see `WfSolver::unsatisfied_impl_obligations`.
+    pub fn unsatisfied_impl_obligations(
+        &self,
+        impl_id: ImplId<ChalkIr>,
+    ) -> Result<Vec<Goal<ChalkIr>>, ChalkError> {
+        let program = self.program_ir()?;
+        let solver_choice = self.solver_choice();
+        Ok(tls::set_current_program(&program, || {
+            let solver_builder = || solver_choice.into_solver();
+            WfSolver::new(self, &solver_builder).unsatisfied_impl_obligations(impl_id)
+        }))
+    }
+
+    /// Returns the `Copy` impls whose self type needs drop glue; see
+    /// `WfSolver::check_copy_drop_conflict`.
+    pub fn check_copy_drop_conflict(&self) -> Result<Vec<WfError<ChalkIr>>, ChalkError> {
+        let program = self.program_ir()?;
+        let solver_choice = self.solver_choice();
+        Ok(tls::set_current_program(&program, || {
+            let solver_builder = || solver_choice.into_solver();
+            WfSolver::new(self, &solver_builder).check_copy_drop_conflict()
+        }))
+    }
+
+    /// Returns the unproductive cycle that `goal` can only be proven
+    /// through, if any; see `check_unproductive_cycles`. The solvers just
+    /// fail to prove such goals, so this explains why.
+    pub fn unproductive_cycle(
+        &self,
+        goal: &UCanonical<InEnvironment<Goal<ChalkIr>>>,
+    ) -> Option<CycleError<ChalkIr>> {
+        chalk_solve::clauses::cycles::check_unproductive_cycles(self, goal).err()
+    }
+
     pub fn solve(
         &self,
         goal: &UCanonical<InEnvironment<Goal<ChalkIr>>>,
@@ -91,6 +153,17 @@ impl RustIrDatabase<ChalkIr> for ChalkDatabase {
         self.program_ir().unwrap().associated_ty_data(ty)
     }
 
+    fn associated_const_data(
+        &self,
+        id: AssocConstId<ChalkIr>,
+    ) -> Arc<AssociatedConstDatum<ChalkIr>> {
+        self.program_ir().unwrap().associated_const_data(id)
+    }
+
+    fn default_methods(&self, trait_id: TraitId<ChalkIr>) -> Vec<Arc<DefaultMethodDatum<ChalkIr>>> {
+        self.program_ir().unwrap().default_methods(trait_id)
+    }
+
     fn trait_datum(&self, id: TraitId<ChalkIr>) -> Arc<TraitDatum<ChalkIr>> {
         self.program_ir().unwrap().trait_datum(id)
     }
@@ -118,6 +191,10 @@ impl RustIrDatabase<ChalkIr> for ChalkDatabase {
         self.program_ir().unwrap().adt_datum(id)
     }
 
+    fn adt_ids(&self) -> Vec<AdtId<ChalkIr>> {
+        self.program_ir().unwrap().adt_ids()
+    }
+
     fn generator_datum(&self, id: GeneratorId<ChalkIr>) -> Arc<GeneratorDatum<ChalkIr>> {
         self.program_ir().unwrap().generator_datum(id)
     }
@@ -160,6 +237,16 @@ impl RustIrDatabase<ChalkIr> for ChalkDatabase {
             .impl_provided_for(auto_trait_id, ty)
     }
 
+    fn impls_provided_for(
+        &self,
+        auto_trait_id: TraitId<ChalkIr>,
+        ty: &TyKind<ChalkIr>,
+    ) -> Vec<ImplId<ChalkIr>> {
+        self.program_ir()
+            .unwrap()
+            .impls_provided_for(auto_trait_id, ty)
+    }
+
     fn well_known_trait_id(&self, well_known_trait: WellKnownTrait) -> Option<TraitId<ChalkIr>> {
         self.program_ir()
             .unwrap()
@@ -170,7 +257,7 @@ impl RustIrDatabase<ChalkIr> for ChalkDatabase {
         &self,
         environment: &Environment<ChalkIr>,
     ) -> ProgramClauses<ChalkIr> {
-        chalk_solve::program_clauses_for_env(self, environment)
+        self.elaborated_env_clauses(environment.clone())
     }
 
     fn interner(&self) -> ChalkIr {
@@ -235,6 +322,10 @@ impl RustIrDatabase<ChalkIr> for ChalkDatabase {
         self.program_ir().unwrap().assoc_type_name(assoc_ty_id)
     }
 
+    fn assoc_const_name(&self, assoc_const_id: AssocConstId<ChalkIr>) -> String {
+        self.program_ir().unwrap().assoc_const_name(assoc_const_id)
+    }
+
     fn opaque_type_name(&self, opaque_ty_id: OpaqueTyId<ChalkIr>) -> String {
         self.program_ir().unwrap().opaque_type_name(opaque_ty_id)
     }
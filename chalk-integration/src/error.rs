@@ -66,6 +66,13 @@ pub enum RustIrError {
     InvalidFundamentalTypesParameters(Identifier),
     NegativeImplAssociatedValues(Identifier),
     MissingAssociatedType(Identifier),
+    MissingAssociatedConst(Identifier),
+    MissingAssociatedConstValue {
+        trait_name: Identifier,
+        const_name: Atom,
+    },
+    IncorrectAssociatedConstType(Identifier),
+    TraitAliasImpl(Identifier),
     IncorrectNumberOfVarianceParameters {
         identifier: Identifier,
         expected: usize,
@@ -142,6 +149,25 @@ impl std::fmt::Display for RustIrError {
             RustIrError::MissingAssociatedType(name) => {
                 write!(f, "no associated type `{}` defined in trait", name)
             }
+            RustIrError::MissingAssociatedConst(name) => {
+                write!(f, "no associated const `{}` defined in trait", name)
+            }
+            RustIrError::MissingAssociatedConstValue {
+                trait_name,
+                const_name,
+            } => write!(
+                f,
+                "impl of trait `{}` does not provide associated const `{}`",
+                trait_name, const_name
+            ),
+            RustIrError::IncorrectAssociatedConstType(name) => write!(
+                f,
+                "value of associated const `{}` does not have the type declared in the trait",
+                name
+            ),
+            RustIrError::TraitAliasImpl(name) => {
+                write!(f, "cannot implement trait alias `{}`", name)
+            }
             RustIrError::IncorrectNumberOfVarianceParameters {
                 identifier,
                 expected,
@@ -96,7 +96,7 @@ impl SolverChoice {
             SolverChoice::SLG {
                 max_size,
                 expected_answers,
-            } => Box::new(SLGSolver::new(max_size, expected_answers)),
+            } => Box::new(SLGSolver::new(max_size, expected_answers, None)),
             SolverChoice::Recursive {
                 overflow_depth,
                 caching_enabled,
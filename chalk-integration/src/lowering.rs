@@ -106,6 +106,13 @@ lower_param_map!(
         Atom::from(SELF),
     ))
 );
+lower_param_map!(
+    TraitAliasDefn,
+    Some(chalk_ir::WithKind::new(
+        chalk_ir::VariableKind::Ty(TyVariableKind::General),
+        Atom::from(SELF),
+    ))
+);
 
 fn get_type_of_usize() -> chalk_ir::Ty<ChalkIr> {
     chalk_ir::TyKind::Scalar(chalk_ir::Scalar::Uint(chalk_ir::UintTy::Usize)).intern(ChalkIr)
@@ -183,6 +190,9 @@ impl LowerWithEnv for WhereClause {
                     },
                 )]
             }
+            WhereClause::NotImplemented { trait_ref } => {
+                vec![chalk_ir::WhereClause::NotImplemented(trait_ref.lower(env)?)]
+            }
         })
     }
 }
@@ -218,6 +228,12 @@ impl LowerWithEnv for DomainGoal {
                     ty: ty.lower(env)?,
                 })]
             }
+            DomainGoal::ConstEq { projection, value } => {
+                vec![chalk_ir::DomainGoal::ConstEq(chalk_ir::ConstEq {
+                    projection: projection.lower_const_projection(env)?,
+                    value: value.lower(env)?,
+                })]
+            }
             DomainGoal::TyWellFormed { ty } => vec![chalk_ir::DomainGoal::WellFormed(
                 chalk_ir::WellFormed::Ty(ty.lower(env)?),
             )],
@@ -599,14 +615,36 @@ impl Lower for TraitFlags {
     type Lowered = rust_ir::TraitFlags;
 
     fn lower(&self) -> Self::Lowered {
-        rust_ir::TraitFlags {
-            auto: self.auto,
-            marker: self.marker,
-            upstream: self.upstream,
-            fundamental: self.fundamental,
-            non_enumerable: self.non_enumerable,
-            coinductive: self.coinductive,
+        let mut flags = rust_ir::TraitFlags::default();
+        flags.auto = self.auto;
+        flags.marker = self.marker;
+        flags.upstream = self.upstream;
+        flags.fundamental = self.fundamental;
+        flags.non_enumerable = self.non_enumerable;
+        flags.coinductive = self.coinductive;
+        flags
+    }
+}
+
+trait LowerConstProjection {
+    fn lower_const_projection(&self, env: &Env) -> LowerResult<chalk_ir::ConstProjection<ChalkIr>>;
+}
+
+impl LowerConstProjection for ProjectionTy {
+    fn lower_const_projection(&self, env: &Env) -> LowerResult<chalk_ir::ConstProjection<ChalkIr>> {
+        let trait_ref = self.trait_ref.lower(env)?;
+        let associated_const_id = env.lookup_associated_const(trait_ref.trait_id, &self.name)?;
+        if !self.args.is_empty() {
+            Err(RustIrError::IncorrectNumberOfAssociatedTypeParameters {
+                identifier: self.name.clone(),
+                expected: 0,
+                actual: self.args.len(),
+            })?;
         }
+        Ok(chalk_ir::ConstProjection {
+            associated_const_id,
+            substitution: trait_ref.substitution,
+        })
     }
 }
 
@@ -808,6 +846,7 @@ impl LowerWithEnv for Ty {
             Ty::Str => chalk_ir::TyKind::Str.intern(interner),
 
             Ty::Never => chalk_ir::TyKind::Never.intern(interner),
+            Ty::Error => chalk_ir::TyKind::Error.intern(interner),
         })
     }
 }
@@ -895,7 +934,9 @@ impl LowerWithEnv for (&Impl, ImplId<ChalkIr>, &AssociatedTyValueIds) {
             let trait_ref = impl_.trait_ref.lower(env)?;
             debug!(?trait_ref);
 
-            if !polarity.is_positive() && !impl_.assoc_ty_values.is_empty() {
+            if !polarity.is_positive()
+                && (!impl_.assoc_ty_values.is_empty() || !impl_.assoc_const_values.is_empty())
+            {
                 Err(RustIrError::NegativeImplAssociatedValues(
                     impl_.trait_ref.trait_name.clone(),
                 ))?;
@@ -920,11 +961,26 @@ impl LowerWithEnv for (&Impl, ImplId<ChalkIr>, &AssociatedTyValueIds) {
 
         debug!(?associated_ty_value_ids);
 
+        // associated const values are bound by the impl's parameters
+        let trait_id = binders.skip_binders().trait_ref.trait_id;
+        let associated_const_values = impl_
+            .assoc_const_values
+            .iter()
+            .map(|acv| {
+                Ok(rust_ir::AssociatedConstValue {
+                    impl_id: *impl_id,
+                    associated_const_id: env.lookup_associated_const(trait_id, &acv.name)?,
+                    value: env.in_binders(impl_.all_parameters(), |env| acv.value.lower(env))?,
+                })
+            })
+            .collect::<LowerResult<_>>()?;
+
         Ok(rust_ir::ImplDatum {
             polarity,
             binders,
             impl_type: impl_.impl_type.lower(),
             associated_ty_value_ids,
+            associated_const_values,
         })
     }
 }
@@ -998,11 +1054,18 @@ impl LowerWithEnv for (&TraitDefn, chalk_ir::TraitId<ChalkIr>) {
             .map(|defn| env.lookup_associated_ty(*trait_id, &defn.name).unwrap().id)
             .collect();
 
+        let associated_const_ids: Vec<_> = trait_defn
+            .assoc_const_defns
+            .iter()
+            .map(|defn| env.lookup_associated_const(*trait_id, &defn.name).unwrap())
+            .collect();
+
         let trait_datum = rust_ir::TraitDatum {
             id: *trait_id,
             binders,
             flags: trait_defn.flags.lower(),
             associated_ty_ids,
+            associated_const_ids,
             well_known: trait_defn.well_known.map(|def| def.lower()),
         };
 
@@ -1012,6 +1075,47 @@ impl LowerWithEnv for (&TraitDefn, chalk_ir::TraitId<ChalkIr>) {
     }
 }
 
+impl LowerWithEnv for (&TraitAliasDefn, chalk_ir::TraitId<ChalkIr>) {
+    type Lowered = rust_ir::TraitDatum<ChalkIr>;
+
+    /// A trait alias lowers to a trait whose where clauses are the alias's
+    /// bounds applied to `Self`, marked with the `alias` flag.
+    fn lower(&self, env: &Env) -> LowerResult<Self::Lowered> {
+        let (alias_defn, trait_id) = self;
+        let interner = env.interner();
+
+        let binders = env.in_binders(alias_defn.all_parameters(), |env| {
+            // `Self` is the first parameter of the alias.
+            let self_ty = chalk_ir::TyKind::BoundVar(BoundVar::new(DebruijnIndex::INNERMOST, 0))
+                .intern(interner);
+            Ok(rust_ir::TraitDatumBound {
+                where_clauses: alias_defn
+                    .bounds
+                    .lower(env)?
+                    .iter()
+                    .flat_map(|qil| qil.into_where_clauses(interner, self_ty.clone()))
+                    .collect(),
+            })
+        })?;
+
+        let mut flags = rust_ir::TraitFlags::default();
+        flags.alias = true;
+
+        let trait_datum = rust_ir::TraitDatum {
+            id: *trait_id,
+            binders,
+            flags,
+            associated_ty_ids: vec![],
+            associated_const_ids: vec![],
+            well_known: None,
+        };
+
+        debug!(?trait_datum);
+
+        Ok(trait_datum)
+    }
+}
+
 pub fn lower_goal(goal: &Goal, program: &LoweredProgram) -> LowerResult<chalk_ir::Goal<ChalkIr>> {
     let interner = ChalkIr;
     let associated_ty_lookups: BTreeMap<_, _> = program
@@ -1031,6 +1135,14 @@ pub fn lower_goal(goal: &Goal, program: &LoweredProgram) -> LowerResult<chalk_ir
         })
         .collect();
 
+    let associated_const_lookups: BTreeMap<_, _> = program
+        .associated_const_data
+        .iter()
+        .map(|(&associated_const_id, datum)| {
+            ((datum.trait_id, datum.name.clone()), associated_const_id)
+        })
+        .collect();
+
     let auto_traits = program
         .trait_data
         .iter()
@@ -1051,6 +1163,7 @@ pub fn lower_goal(goal: &Goal, program: &LoweredProgram) -> LowerResult<chalk_ir
         trait_kinds: &program.trait_kinds,
         opaque_ty_kinds: &program.opaque_ty_kinds,
         associated_ty_lookups: &associated_ty_lookups,
+        associated_const_lookups: &associated_const_lookups,
         foreign_ty_ids: &program.foreign_ty_ids,
         parameter_map: BTreeMap::new(),
         auto_traits: &auto_traits,
@@ -1134,6 +1247,7 @@ impl Lower for WellKnownTrait {
             WellKnownTrait::CoerceUnsized => rust_ir::WellKnownTrait::CoerceUnsized,
             WellKnownTrait::DiscriminantKind => rust_ir::WellKnownTrait::DiscriminantKind,
             WellKnownTrait::Generator => rust_ir::WellKnownTrait::Generator,
+            WellKnownTrait::Tuple => rust_ir::WellKnownTrait::Tuple,
         }
     }
 }
@@ -1,7 +1,8 @@
 use chalk_ir::cast::Cast;
 use chalk_ir::{
-    self, AdtId, AssocTypeId, BoundVar, ClosureId, DebruijnIndex, FnDefId, ForeignDefId,
-    GeneratorId, ImplId, OpaqueTyId, TraitId, TyVariableKind, VariableKinds,
+    self, AdtId, AssocConstId, AssocTypeId, BoundVar, ClosureId, DebruijnIndex, FnDefId,
+    ForeignDefId, GeneratorId, ImplId, OpaqueTyId, Substitution, TraitId, TraitRef, TyVariableKind,
+    VariableKinds,
 };
 use chalk_parse::ast::*;
 use chalk_solve::rust_ir::{
@@ -24,6 +25,7 @@ pub(super) struct ProgramLowerer {
     next_item_index: u32,
 
     associated_ty_lookups: AssociatedTyLookups,
+    associated_const_lookups: AssociatedConstLookups,
     associated_ty_value_ids: AssociatedTyValueIds,
     adt_ids: AdtIds,
     fn_def_ids: FnDefIds,
@@ -49,7 +51,8 @@ impl ProgramLowerer {
         RawId { index }
     }
 
-    /// Create ids for associated type declarations and values
+    /// Create ids for associated type declarations and values, and for
+    /// associated const declarations
     pub fn extract_associated_types(
         &mut self,
         program: &Program,
@@ -58,7 +61,9 @@ impl ProgramLowerer {
         for (item, &raw_id) in program.items.iter().zip(raw_ids) {
             match item {
                 Item::TraitDefn(d) => {
-                    if d.flags.auto && !d.assoc_ty_defns.is_empty() {
+                    if d.flags.auto
+                        && !(d.assoc_ty_defns.is_empty() && d.assoc_const_defns.is_empty())
+                    {
                         Err(RustIrError::AutoTraitAssociatedTypes(d.name.clone()))?;
                     }
                     for defn in &d.assoc_ty_defns {
@@ -70,6 +75,11 @@ impl ProgramLowerer {
                         self.associated_ty_lookups
                             .insert((TraitId(raw_id), defn.name.str.clone()), lookup);
                     }
+                    for defn in &d.assoc_const_defns {
+                        let id = AssocConstId(self.next_item_id());
+                        self.associated_const_lookups
+                            .insert((TraitId(raw_id), defn.name.str.clone()), id);
+                    }
                 }
 
                 Item::Impl(d) => {
@@ -118,6 +128,13 @@ impl ProgramLowerer {
                         self.object_safe_traits.insert(id);
                     }
                 }
+                Item::TraitAlias(defn) => {
+                    let type_kind = defn.lower_type_kind()?;
+                    let id = TraitId(raw_id);
+                    self.trait_ids.insert(type_kind.name.clone(), id);
+                    self.trait_kinds.insert(id, type_kind);
+                    self.auto_traits.insert(id, false);
+                }
                 Item::OpaqueTyDefn(defn) => {
                     let type_kind = defn.lower_type_kind()?;
                     let id = OpaqueTyId(raw_id);
@@ -154,6 +171,8 @@ impl ProgramLowerer {
         let mut impl_data = BTreeMap::new();
         let mut associated_ty_data = BTreeMap::new();
         let mut associated_ty_values = BTreeMap::new();
+        let mut associated_const_data = BTreeMap::new();
+        let mut default_method_data = BTreeMap::new();
         let mut opaque_ty_data = BTreeMap::new();
         let mut generator_data = BTreeMap::new();
         let mut generator_witness_data = BTreeMap::new();
@@ -175,6 +194,7 @@ impl ProgramLowerer {
                 generator_ids: &self.generator_ids,
                 generator_kinds: &self.generator_kinds,
                 associated_ty_lookups: &self.associated_ty_lookups,
+                associated_const_lookups: &self.associated_const_lookups,
                 parameter_map: BTreeMap::new(),
                 auto_traits: &self.auto_traits,
                 foreign_ty_ids: &self.foreign_ty_ids,
@@ -297,6 +317,11 @@ impl ProgramLowerer {
                             Ok(rust_ir::AssociatedTyDatumBound {
                                 bounds: assoc_ty_defn.bounds.lower(&env)?,
                                 where_clauses: assoc_ty_defn.where_clauses.lower(&env)?,
+                                default: assoc_ty_defn
+                                    .default
+                                    .as_ref()
+                                    .map(|ty| ty.lower(env))
+                                    .transpose()?,
                             })
                         })?;
 
@@ -310,6 +335,63 @@ impl ProgramLowerer {
                             }),
                         );
                     }
+
+                    for assoc_const_defn in &trait_defn.assoc_const_defns {
+                        let id = self.associated_const_lookups
+                            [&(trait_id, assoc_const_defn.name.str.clone())];
+                        let ty = empty_env.in_binders(trait_defn.all_parameters(), |env| {
+                            assoc_const_defn.ty.lower(env)
+                        })?;
+                        associated_const_data.insert(
+                            id,
+                            Arc::new(rust_ir::AssociatedConstDatum {
+                                trait_id,
+                                id,
+                                name: assoc_const_defn.name.str.clone(),
+                                ty,
+                            }),
+                        );
+                    }
+
+                    // As for associated types, the method's parameters come
+                    // first, followed by those of the trait.
+                    let mut default_methods = Vec::new();
+                    for method in &trait_defn.default_methods {
+                        let n_method_params = method.all_parameters().len();
+                        let mut variable_kinds = method.all_parameters();
+                        variable_kinds.extend(trait_defn.all_parameters());
+
+                        let where_clauses = empty_env
+                            .in_binders(variable_kinds, |env| method.where_clauses.lower(env))?;
+                        let trait_ref = TraitRef {
+                            trait_id,
+                            substitution: Substitution::from_iter(
+                                ChalkIr,
+                                where_clauses
+                                    .identity_substitution(ChalkIr)
+                                    .iter(ChalkIr)
+                                    .skip(n_method_params)
+                                    .cloned(),
+                            ),
+                        };
+                        let binders =
+                            where_clauses.map(|where_clauses| rust_ir::DefaultMethodDatumBound {
+                                trait_ref,
+                                where_clauses,
+                            });
+
+                        default_methods.push(Arc::new(rust_ir::DefaultMethodDatum {
+                            trait_id,
+                            name: method.name.str.clone(),
+                            binders,
+                        }));
+                    }
+                    default_method_data.insert(trait_id, default_methods);
+                }
+                Item::TraitAlias(ref alias_defn) => {
+                    let trait_id = TraitId(raw_id);
+                    let trait_datum = (alias_defn, trait_id).lower(&empty_env)?;
+                    trait_data.insert(trait_id, Arc::new(trait_datum));
                 }
                 Item::Impl(ref impl_defn) => {
                     let impl_id = ImplId(raw_id);
@@ -468,6 +550,24 @@ impl ProgramLowerer {
             }
         }
 
+        // Impls can only be checked against their trait once every trait has
+        // been lowered.
+        for (item, &raw_id) in program.items.iter().zip(raw_ids) {
+            if let Item::Impl(ref impl_defn) = item {
+                let trait_id = impl_data[&ImplId(raw_id)].trait_id();
+                if trait_data[&trait_id].flags.alias {
+                    Err(RustIrError::TraitAliasImpl(
+                        impl_defn.trait_ref.trait_name.clone(),
+                    ))?;
+                }
+                check_associated_const_values(
+                    impl_defn,
+                    &impl_data[&ImplId(raw_id)],
+                    &associated_const_data,
+                )?;
+            }
+        }
+
         Ok(LoweredProgram {
             adt_ids: self.adt_ids,
             fn_def_ids: self.fn_def_ids,
@@ -494,6 +594,8 @@ impl ProgramLowerer {
             impl_data,
             associated_ty_values,
             associated_ty_data,
+            associated_const_data,
+            default_method_data,
             opaque_ty_ids: self.opaque_ty_ids,
             opaque_ty_kinds: self.opaque_ty_kinds,
             opaque_ty_data,
@@ -505,6 +607,54 @@ impl ProgramLowerer {
     }
 }
 
+/// Checks that a positive impl provides a value for each associated const of
+/// its trait, and that each value has the type the trait declares.
+fn check_associated_const_values(
+    impl_defn: &Impl,
+    impl_datum: &rust_ir::ImplDatum<ChalkIr>,
+    associated_const_data: &BTreeMap<
+        AssocConstId<ChalkIr>,
+        Arc<rust_ir::AssociatedConstDatum<ChalkIr>>,
+    >,
+) -> LowerResult<()> {
+    if !impl_datum.is_positive() {
+        return Ok(());
+    }
+
+    let interner = ChalkIr;
+    let trait_ref = &impl_datum.binders.skip_binders().trait_ref;
+    for const_datum in associated_const_data
+        .values()
+        .filter(|datum| datum.trait_id == trait_ref.trait_id)
+    {
+        let const_value = impl_datum
+            .associated_const_values
+            .iter()
+            .find(|value| value.associated_const_id == const_datum.id)
+            .ok_or_else(|| RustIrError::MissingAssociatedConstValue {
+                trait_name: impl_defn.trait_ref.trait_name.clone(),
+                const_name: const_datum.name.clone(),
+            })?;
+
+        // Both types are bound by the impl's parameters.
+        let expected_ty = const_datum
+            .ty
+            .clone()
+            .substitute(interner, &trait_ref.substitution);
+        if const_value.value.skip_binders().data(interner).ty != expected_ty {
+            let name = impl_defn
+                .assoc_const_values
+                .iter()
+                .find(|acv| acv.name.str == const_datum.name)
+                .unwrap()
+                .name
+                .clone();
+            Err(RustIrError::IncorrectAssociatedConstType(name))?;
+        }
+    }
+    Ok(())
+}
+
 trait LowerTypeKind {
     fn lower_type_kind(&self) -> LowerResult<TypeKind>;
 }
@@ -540,6 +690,11 @@ lower_type_kind!(TraitDefn, Trait, |defn: &TraitDefn| defn
     .iter()
     .map(|k| k.lower())
     .collect::<Vec<_>>());
+lower_type_kind!(TraitAliasDefn, Trait, |defn: &TraitAliasDefn| defn
+    .variable_kinds
+    .iter()
+    .map(|k| k.lower())
+    .collect::<Vec<_>>());
 lower_type_kind!(OpaqueTyDefn, Opaque, |defn: &OpaqueTyDefn| defn
     .variable_kinds
     .iter()
@@ -3,15 +3,18 @@ use crate::{tls, Identifier, TypeKind};
 use chalk_ir::{could_match::CouldMatch, UnificationDatabase};
 use chalk_ir::{debug::Angle, Variance};
 use chalk_ir::{
-    debug::SeparatorTraitRef, AdtId, AliasTy, AssocTypeId, Binders, CanonicalVarKinds, ClosureId,
-    FnDefId, ForeignDefId, GeneratorId, GenericArg, Goal, Goals, ImplId, IntTy, Lifetime, OpaqueTy,
-    OpaqueTyId, ProgramClause, ProgramClauseImplication, ProgramClauses, ProjectionTy, Scalar,
-    Substitution, TraitId, Ty, TyKind, UintTy, Variances,
+    debug::SeparatorTraitRef, AdtId, AliasTy, AssocConstId, AssocTypeId, Binders,
+    CanonicalVarKinds, ClosureId, FnDefId, ForeignDefId, GeneratorId, GenericArg, Goal, Goals,
+    ImplId, IntTy, Lifetime, OpaqueTy, OpaqueTyId, ProgramClause, ProgramClauseImplication,
+    ProgramClauses, ProjectionTy, Scalar, Substitution, TraitId, TraitRef, Ty, TyKind, UintTy,
+    Variances,
 };
+use chalk_solve::clauses::auto_trait_impl_suppressed;
 use chalk_solve::rust_ir::{
-    AdtDatum, AdtRepr, AssociatedTyDatum, AssociatedTyValue, AssociatedTyValueId, ClosureKind,
-    FnDefDatum, FnDefInputsAndOutputDatum, GeneratorDatum, GeneratorWitnessDatum, ImplDatum,
-    ImplType, OpaqueTyDatum, TraitDatum, WellKnownTrait,
+    AdtDatum, AdtRepr, AssociatedConstDatum, AssociatedTyDatum, AssociatedTyValue,
+    AssociatedTyValueId, ClosureKind, DefaultMethodDatum, FnDefDatum, FnDefInputsAndOutputDatum,
+    GeneratorDatum, GeneratorWitnessDatum, ImplDatum, ImplType, OpaqueTyDatum, TraitDatum,
+    WellKnownTrait,
 };
 use chalk_solve::split::Split;
 use chalk_solve::RustIrDatabase;
@@ -97,6 +100,12 @@ pub struct Program {
     /// For each associated ty declaration `type Foo` found in a trait:
     pub associated_ty_data: BTreeMap<AssocTypeId<ChalkIr>, Arc<AssociatedTyDatum<ChalkIr>>>,
 
+    /// For each associated const declaration `const N: T` found in a trait:
+    pub associated_const_data: BTreeMap<AssocConstId<ChalkIr>, Arc<AssociatedConstDatum<ChalkIr>>>,
+
+    /// For each trait, the methods with a default body found in it:
+    pub default_method_data: BTreeMap<TraitId<ChalkIr>, Vec<Arc<DefaultMethodDatum<ChalkIr>>>>,
+
     /// For each user-specified clause
     pub custom_clauses: Vec<ProgramClause<ChalkIr>>,
 
@@ -116,6 +125,42 @@ impl Program {
             .map(|(&impl_id, _)| impl_id)
             .collect()
     }
+
+    /// Returns the trait refs that `trait_id` may be implemented for,
+    /// without running the solver: the head of every positive impl and,
+    /// if it is an auto trait, one head per ADT whose default impl is not
+    /// replaced by an explicit one.
+    /// The latter are listed even if some field type keeps the auto impl
+    /// from applying.
+    pub fn possible_impls_for_trait(
+        &self,
+        trait_id: TraitId<ChalkIr>,
+    ) -> Vec<Binders<TraitRef<ChalkIr>>> {
+        let interner = self.interner();
+        let mut heads: Vec<_> = self
+            .impl_data
+            .values()
+            .filter(|impl_datum| impl_datum.trait_id() == trait_id && impl_datum.is_positive())
+            .map(|impl_datum| impl_datum.binders.map_ref(|bound| bound.trait_ref.clone()))
+            .collect();
+
+        if self.trait_data[&trait_id].is_auto_trait() {
+            for (&adt_id, adt_datum) in &self.adt_data {
+                let self_ty =
+                    TyKind::Adt(adt_id, adt_datum.binders.identity_substitution(interner));
+                if auto_trait_impl_suppressed(self, trait_id, &self_ty) {
+                    continue;
+                }
+                let trait_ref = TraitRef {
+                    trait_id,
+                    substitution: Substitution::from1(interner, self_ty.intern(interner)),
+                };
+                heads.push(Binders::new(adt_datum.binders.binders.clone(), trait_ref));
+            }
+        }
+
+        heads
+    }
 }
 
 impl tls::DebugContext for Program {
@@ -161,6 +206,20 @@ impl tls::DebugContext for Program {
         }
     }
 
+    fn debug_assoc_const_id(
+        &self,
+        assoc_const_id: AssocConstId<ChalkIr>,
+        fmt: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        if let Some(d) = self.associated_const_data.get(&assoc_const_id) {
+            write!(fmt, "({:?}::{})", d.trait_id, d.name)
+        } else {
+            fmt.debug_struct("InvalidAssocConstId")
+                .field("index", &assoc_const_id.0)
+                .finish()
+        }
+    }
+
     fn debug_opaque_ty_id(
         &self,
         opaque_ty_id: OpaqueTyId<ChalkIr>,
@@ -388,6 +447,20 @@ impl RustIrDatabase<ChalkIr> for Program {
         self.associated_ty_data[&ty].clone()
     }
 
+    fn associated_const_data(
+        &self,
+        id: AssocConstId<ChalkIr>,
+    ) -> Arc<AssociatedConstDatum<ChalkIr>> {
+        self.associated_const_data[&id].clone()
+    }
+
+    fn default_methods(&self, trait_id: TraitId<ChalkIr>) -> Vec<Arc<DefaultMethodDatum<ChalkIr>>> {
+        self.default_method_data
+            .get(&trait_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     fn trait_datum(&self, id: TraitId<ChalkIr>) -> Arc<TraitDatum<ChalkIr>> {
         self.trait_data[&id].clone()
     }
@@ -415,6 +488,10 @@ impl RustIrDatabase<ChalkIr> for Program {
         self.adt_data[&id].clone()
     }
 
+    fn adt_ids(&self) -> Vec<AdtId<ChalkIr>> {
+        self.adt_data.keys().copied().collect()
+    }
+
     fn generator_datum(&self, id: GeneratorId<ChalkIr>) -> Arc<GeneratorDatum<ChalkIr>> {
         self.generator_data[&id].clone()
     }
@@ -469,51 +546,59 @@ impl RustIrDatabase<ChalkIr> for Program {
             .collect()
     }
 
-    fn impl_provided_for(
+    fn impl_provided_for(&self, auto_trait_id: TraitId<ChalkIr>, ty: &TyKind<ChalkIr>) -> bool {
+        !self.impls_provided_for(auto_trait_id, ty).is_empty()
+    }
+
+    fn impls_provided_for(
         &self,
         auto_trait_id: TraitId<ChalkIr>,
         impl_ty: &TyKind<ChalkIr>,
-    ) -> bool {
+    ) -> Vec<ImplId<ChalkIr>> {
         let interner = self.interner();
 
-        // we don't compare actual substitutions as
-        // - given a `struct S<T>`; an implementation for `S<A>` should suppress an auto impl for `S<B>`, and
-        // - an implementation for `[A]` should suppress an auto impl for `[B]`, and
-        // - an implementation for `(A, B, C)` should suppress an auto impl for `(D, E, F)`
-        // this may change later
-        self.impl_data.values().any(|impl_datum| {
-            if impl_datum.trait_id() != auto_trait_id {
-                return false;
-            }
+        // we don't compare actual substitutions here: whether e.g. an
+        // implementation for `S<A>` suppresses an auto impl for `S<B>` is up
+        // to the caller
+        self.impl_data
+            .iter()
+            .filter(|(_, impl_datum)| {
+                if impl_datum.trait_id() != auto_trait_id {
+                    return false;
+                }
 
-            let ty = impl_datum
-                .binders
-                .skip_binders()
-                .trait_ref
-                .self_type_parameter(interner);
-            match (impl_ty, ty.kind(interner)) {
-                (TyKind::Adt(id_a, _), TyKind::Adt(id_b, _)) => id_a == id_b,
-                (TyKind::AssociatedType(id_a, _), TyKind::AssociatedType(id_b, _)) => id_a == id_b,
-                (TyKind::Scalar(scalar_a), TyKind::Scalar(scalar_b)) => scalar_a == scalar_b,
-                (TyKind::Str, TyKind::Str) => true,
-                (TyKind::Tuple(arity_a, _), TyKind::Tuple(arity_b, _)) => arity_a == arity_b,
-                (TyKind::OpaqueType(id_a, _), TyKind::OpaqueType(id_b, _)) => id_a == id_b,
-                (TyKind::Slice(_), TyKind::Slice(_)) => true,
-                (TyKind::FnDef(id_a, _), TyKind::FnDef(id_b, _)) => id_a == id_b,
-                (TyKind::Ref(id_a, _, _), TyKind::Ref(id_b, _, _)) => id_a == id_b,
-                (TyKind::Raw(id_a, _), TyKind::Raw(id_b, _)) => id_a == id_b,
-                (TyKind::Never, TyKind::Never) => true,
-                (TyKind::Array(_, _), TyKind::Array(_, _)) => true,
-                (TyKind::Closure(id_a, _), TyKind::Closure(id_b, _)) => id_a == id_b,
-                (TyKind::Generator(id_a, _), TyKind::Generator(id_b, _)) => id_a == id_b,
-                (TyKind::GeneratorWitness(id_a, _), TyKind::GeneratorWitness(id_b, _)) => {
-                    id_a == id_b
+                let ty = impl_datum
+                    .binders
+                    .skip_binders()
+                    .trait_ref
+                    .self_type_parameter(interner);
+                match (impl_ty, ty.kind(interner)) {
+                    (TyKind::Adt(id_a, _), TyKind::Adt(id_b, _)) => id_a == id_b,
+                    (TyKind::AssociatedType(id_a, _), TyKind::AssociatedType(id_b, _)) => {
+                        id_a == id_b
+                    }
+                    (TyKind::Scalar(scalar_a), TyKind::Scalar(scalar_b)) => scalar_a == scalar_b,
+                    (TyKind::Str, TyKind::Str) => true,
+                    (TyKind::Tuple(arity_a, _), TyKind::Tuple(arity_b, _)) => arity_a == arity_b,
+                    (TyKind::OpaqueType(id_a, _), TyKind::OpaqueType(id_b, _)) => id_a == id_b,
+                    (TyKind::Slice(_), TyKind::Slice(_)) => true,
+                    (TyKind::FnDef(id_a, _), TyKind::FnDef(id_b, _)) => id_a == id_b,
+                    (TyKind::Ref(id_a, _, _), TyKind::Ref(id_b, _, _)) => id_a == id_b,
+                    (TyKind::Raw(id_a, _), TyKind::Raw(id_b, _)) => id_a == id_b,
+                    (TyKind::Never, TyKind::Never) => true,
+                    (TyKind::Array(_, _), TyKind::Array(_, _)) => true,
+                    (TyKind::Closure(id_a, _), TyKind::Closure(id_b, _)) => id_a == id_b,
+                    (TyKind::Generator(id_a, _), TyKind::Generator(id_b, _)) => id_a == id_b,
+                    (TyKind::GeneratorWitness(id_a, _), TyKind::GeneratorWitness(id_b, _)) => {
+                        id_a == id_b
+                    }
+                    (TyKind::Foreign(id_a), TyKind::Foreign(id_b)) => id_a == id_b,
+                    (TyKind::Error, TyKind::Error) => true,
+                    (_, _) => false,
                 }
-                (TyKind::Foreign(id_a), TyKind::Foreign(id_b)) => id_a == id_b,
-                (TyKind::Error, TyKind::Error) => true,
-                (_, _) => false,
-            }
-        })
+            })
+            .map(|(&impl_id, _)| impl_id)
+            .collect()
     }
 
     fn well_known_trait_id(&self, well_known_trait: WellKnownTrait) -> Option<TraitId<ChalkIr>> {
@@ -590,6 +675,14 @@ impl RustIrDatabase<ChalkIr> for Program {
             .to_string()
     }
 
+    fn assoc_const_name(&self, assoc_const_id: AssocConstId<ChalkIr>) -> String {
+        self.associated_const_data
+            .get(&assoc_const_id)
+            .unwrap()
+            .name
+            .to_string()
+    }
+
     // Mirrors current (07a63e6d1fabf3560e8e1e17c1d56b10a06152d9) implementation in rustc
     fn discriminant_type(&self, ty: Ty<ChalkIr>) -> Ty<ChalkIr> {
         let interner = self.interner();
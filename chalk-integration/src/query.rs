@@ -8,7 +8,7 @@ use crate::program::Program;
 use crate::program_environment::ProgramEnvironment;
 use crate::tls;
 use crate::SolverChoice;
-use chalk_ir::{Substitution, TraitId};
+use chalk_ir::{Environment, ProgramClauses, Substitution, TraitId};
 use chalk_solve::clauses::builder::ClauseBuilder;
 use chalk_solve::clauses::program_clauses::ToProgramClauses;
 use chalk_solve::coherence::orphan;
@@ -50,6 +50,12 @@ pub trait LoweringDatabase:
     /// The program as logic.
     fn environment(&self) -> Result<Arc<ProgramEnvironment>, ChalkError>;
 
+    /// The clauses of `environment`, closed under implied-bound elaboration
+    /// (see `chalk_solve::program_clauses_for_env`). The solvers ask for these
+    /// once per subgoal, and the elaboration fixpoint is not cheap, so we
+    /// memoize them per environment.
+    fn elaborated_env_clauses(&self, environment: Environment<ChalkIr>) -> ProgramClauses<ChalkIr>;
+
     /// Creates the solver we can use to solve goals. This solver
     /// stores intermediate, cached state, which is why it is behind a
     /// mutex. Moreover, if the set of program clauses change, that
@@ -248,6 +254,13 @@ fn environment(db: &dyn LoweringDatabase) -> Result<Arc<ProgramEnvironment>, Cha
     Ok(Arc::new(ProgramEnvironment::new(program_clauses)))
 }
 
+fn elaborated_env_clauses(
+    db: &dyn LoweringDatabase,
+    environment: Environment<ChalkIr>,
+) -> ProgramClauses<ChalkIr> {
+    chalk_solve::program_clauses_for_env(db.upcast(), &environment)
+}
+
 fn solver(db: &dyn LoweringDatabase) -> ArcEq<Mutex<Box<dyn Solver<ChalkIr>>>> {
     db.salsa_runtime().report_untracked_read();
     let choice = db.solver_choice();
@@ -142,6 +142,12 @@ impl<I: Interner> CastTo<DomainGoal<I>> for Normalize<I> {
     }
 }
 
+impl<I: Interner> CastTo<DomainGoal<I>> for ConstEq<I> {
+    fn cast_to(self, _interner: I) -> DomainGoal<I> {
+        DomainGoal::ConstEq(self)
+    }
+}
+
 impl<I: Interner> CastTo<DomainGoal<I>> for WellFormed<I> {
     fn cast_to(self, _interner: I) -> DomainGoal<I> {
         DomainGoal::WellFormed(self)
@@ -11,6 +11,7 @@ use crate::fold::shift::Shift;
 use crate::fold::{Fold, Folder, Subst, SuperFold};
 use crate::visit::{SuperVisit, Visit, VisitExt, Visitor};
 use chalk_derive::{Fold, HasInterner, SuperVisit, Visit, Zip};
+use std::collections::HashSet;
 use std::marker::PhantomData;
 use std::ops::ControlFlow;
 
@@ -176,6 +177,21 @@ impl<I: Interner> Environment<I> {
         env
     }
 
+    /// Combines the clauses of `self` and `other` into one environment,
+    /// keeping only the first occurrence of each clause.
+    pub fn merge(&self, interner: I, other: &Environment<I>) -> Self {
+        let mut seen = HashSet::new();
+        let clauses = self
+            .clauses
+            .iter(interner)
+            .chain(other.clauses.iter(interner))
+            .filter(|clause| seen.insert(*clause))
+            .cloned();
+        Environment {
+            clauses: ProgramClauses::from_iter(interner, clauses),
+        }
+    }
+
     /// True if any of the clauses in the environment have a consequence of `Compatible`.
     /// Panics if the conditions or constraints of that clause are not empty.
     pub fn has_compatible_clause(&self, interner: I) -> bool {
@@ -384,6 +400,13 @@ pub struct ClauseId<I: Interner>(pub I::DefId);
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AssocTypeId<I: Interner>(pub I::DefId);
 
+/// The id for the associated const member of a trait. The details of the const
+/// can be found by invoking the [`associated_const_data`] method.
+///
+/// [`associated_const_data`]: ../chalk_solve/trait.RustIrDatabase.html#tymethod.associated_const_data
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssocConstId<I: Interner>(pub I::DefId);
+
 /// Id for an opaque type.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct OpaqueTyId<I: Interner>(pub I::DefId);
@@ -742,6 +765,9 @@ impl<I: Interner> TyKind<I> {
                             dyn_flags |= type_outlives.ty.data(interner).flags;
                             dyn_flags |= type_outlives.lifetime.compute_flags(interner);
                         }
+                        WhereClause::NotImplemented(trait_ref) => {
+                            dyn_flags |= trait_ref.substitution.compute_flags(interner)
+                        }
                     }
                 }
                 lifetime_flags | dyn_flags
@@ -1749,6 +1775,11 @@ pub enum WhereClause<I: Interner> {
     LifetimeOutlives(LifetimeOutlives<I>),
     /// Type outlives a lifetime.
     TypeOutlives(TypeOutlives<I>),
+    /// Type is known *not* to implement a trait, either because of a
+    /// negative impl or because it was assumed in the environment. While
+    /// such a fact is in scope, the corresponding `Implemented` goal can
+    /// never be proven.
+    NotImplemented(TraitRef<I>),
 }
 
 impl<I: Interner> Copy for WhereClause<I>
@@ -1851,6 +1882,9 @@ pub enum DomainGoal<I: Interner> {
     /// True if the alias type can be normalized to some other type
     Normalize(Normalize<I>),
 
+    /// True if the associated const projection evaluates to the given const
+    ConstEq(ConstEq<I>),
+
     /// True if a type is considered to have been "defined" by the current crate. This is true for
     /// a `struct Foo { }` but false for a `#[upstream] struct Foo { }`. However, for fundamental types
     /// like `Box<T>`, it is true if `T` is local.
@@ -1914,6 +1948,7 @@ where
     I::InternedSubstitution: Copy,
     I::InternedLifetime: Copy,
     I::InternedType: Copy,
+    I::InternedConst: Copy,
 {
 }
 
@@ -1947,6 +1982,7 @@ impl<I: Interner> WhereClause<I> {
             WhereClause::AliasEq(_) => None,
             WhereClause::LifetimeOutlives(_) => None,
             WhereClause::TypeOutlives(_) => None,
+            WhereClause::NotImplemented(_) => None,
         }
     }
 }
@@ -2033,6 +2069,34 @@ where
 {
 }
 
+/// A projection `<P0 as TraitName<P1..Pn>>::CONST` of an associated const.
+#[derive(Clone, PartialEq, Eq, Hash, Fold, Visit, HasInterner)]
+pub struct ConstProjection<I: Interner> {
+    /// The id for the associated const member.
+    pub associated_const_id: AssocConstId<I>,
+    /// The substitution for the projection, i.e. that of the trait ref.
+    pub substitution: Substitution<I>,
+}
+
+impl<I: Interner> Copy for ConstProjection<I> where I::InternedSubstitution: Copy {}
+
+/// Proves that an associated const projection is equal to the given const.
+/// A projection `T::N` is equal to the const `C` if we can **match it to an
+/// impl** and that impl has a `const N = V` where `C = V`.
+#[derive(Clone, PartialEq, Eq, Hash, Fold, Visit, Zip, HasInterner)]
+#[allow(missing_docs)]
+pub struct ConstEq<I: Interner> {
+    pub projection: ConstProjection<I>,
+    pub value: Const<I>,
+}
+
+impl<I: Interner> Copy for ConstEq<I>
+where
+    I::InternedSubstitution: Copy,
+    I::InternedConst: Copy,
+{
+}
+
 /// Proves **equality** between an alias and a type.
 #[derive(Clone, PartialEq, Eq, Hash, Fold, Visit, Zip)]
 #[allow(missing_docs)]
@@ -2546,6 +2610,50 @@ impl<I: Interner> Goal<I> {
             _ => false,
         }
     }
+
+    /// Returns an equivalent goal with less structure for the solver to work
+    /// through: nested conjunctions are flattened, trivially true subgoals of
+    /// conjunctions are dropped, and quantifiers and implications are
+    /// removed if they bind no variables, have no clauses or wrap a
+    /// trivially true goal.
+    pub fn simplify(&self, interner: I) -> Goal<I> {
+        match self.data(interner) {
+            GoalData::Quantified(kind, subgoal) => {
+                let subgoal = subgoal.map_ref(|goal| goal.simplify(interner));
+                if subgoal.skip_binders().is_trivially_true(interner) {
+                    Goal::all(interner, None)
+                } else if subgoal.binders.is_empty(interner) {
+                    subgoal.substitute(interner, &Substitution::empty(interner))
+                } else {
+                    GoalData::Quantified(*kind, subgoal).intern(interner)
+                }
+            }
+            GoalData::Implies(clauses, subgoal) => {
+                let subgoal = subgoal.simplify(interner);
+                if clauses.is_empty(interner) || subgoal.is_trivially_true(interner) {
+                    subgoal
+                } else {
+                    GoalData::Implies(clauses.clone(), subgoal).intern(interner)
+                }
+            }
+            GoalData::All(goals) => {
+                let mut flattened = Vec::new();
+                for goal in goals.iter(interner) {
+                    let goal = goal.simplify(interner);
+                    match goal.data(interner) {
+                        GoalData::All(goals) => flattened.extend(goals.iter(interner).cloned()),
+                        _ => flattened.push(goal),
+                    }
+                }
+                Goal::all(interner, flattened)
+            }
+            GoalData::Not(subgoal) => subgoal.simplify(interner).negate(interner),
+            GoalData::EqGoal(_)
+            | GoalData::SubtypeGoal(_)
+            | GoalData::DomainGoal(_)
+            | GoalData::CannotProve => self.clone(),
+        }
+    }
 }
 
 impl<I> Goal<I>
@@ -2623,6 +2731,7 @@ where
     I::InternedGoals: Copy,
     I::InternedProgramClauses: Copy,
     I::InternedVariableKinds: Copy,
+    I::InternedConst: Copy,
 {
 }
 
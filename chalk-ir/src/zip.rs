@@ -283,6 +283,7 @@ macro_rules! eq_zip {
 eq_zip!(I => AdtId<I>);
 eq_zip!(I => TraitId<I>);
 eq_zip!(I => AssocTypeId<I>);
+eq_zip!(I => AssocConstId<I>);
 eq_zip!(I => OpaqueTyId<I>);
 eq_zip!(I => GeneratorId<I>);
 eq_zip!(I => ForeignDefId<I>);
@@ -475,6 +476,29 @@ impl<I: Interner> Zip<I> for ProjectionTy<I> {
     }
 }
 
+impl<I: Interner> Zip<I> for ConstProjection<I> {
+    fn zip_with<Z: Zipper<I>>(
+        zipper: &mut Z,
+        variance: Variance,
+        a: &Self,
+        b: &Self,
+    ) -> Fallible<()> {
+        let interner = zipper.interner();
+        Zip::zip_with(
+            zipper,
+            variance,
+            &a.associated_const_id,
+            &b.associated_const_id,
+        )?;
+        zipper.zip_substs(
+            variance,
+            None,
+            a.substitution.as_slice(interner),
+            b.substitution.as_slice(interner),
+        )
+    }
+}
+
 impl<I: Interner> Zip<I> for OpaqueTy<I> {
     fn zip_with<Z: Zipper<I>>(
         zipper: &mut Z,
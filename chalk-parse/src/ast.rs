@@ -24,6 +24,7 @@ pub enum Item {
     FnDefn(FnDefn),
     ClosureDefn(ClosureDefn),
     TraitDefn(TraitDefn),
+    TraitAlias(TraitAliasDefn),
     OpaqueTyDefn(OpaqueTyDefn),
     GeneratorDefn(GeneratorDefn),
     Impl(Impl),
@@ -141,6 +142,9 @@ pub struct TraitDefn {
     pub variable_kinds: Vec<VariableKind>,
     pub where_clauses: Vec<QuantifiedWhereClause>,
     pub assoc_ty_defns: Vec<AssocTyDefn>,
+    pub assoc_const_defns: Vec<AssocConstDefn>,
+    /// Methods with a default body; only their signatures are recorded.
+    pub default_methods: Vec<FnDefn>,
     pub flags: TraitFlags,
     pub well_known: Option<WellKnownTrait>,
 }
@@ -159,6 +163,7 @@ pub enum WellKnownTrait {
     CoerceUnsized,
     DiscriminantKind,
     Generator,
+    Tuple,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -172,12 +177,28 @@ pub struct TraitFlags {
     pub object_safe: bool,
 }
 
+/// A trait alias `trait Alias<P..> = B1 + B2;`, which is implemented for
+/// exactly the types that satisfy all of its bounds.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TraitAliasDefn {
+    pub name: Identifier,
+    pub variable_kinds: Vec<VariableKind>,
+    pub bounds: Vec<QuantifiedInlineBound>,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct AssocTyDefn {
     pub name: Identifier,
     pub variable_kinds: Vec<VariableKind>,
     pub bounds: Vec<QuantifiedInlineBound>,
     pub where_clauses: Vec<QuantifiedWhereClause>,
+    pub default: Option<Ty>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AssocConstDefn {
+    pub name: Identifier,
+    pub ty: Ty,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -267,6 +288,7 @@ pub struct Impl {
     pub polarity: Polarity,
     pub where_clauses: Vec<QuantifiedWhereClause>,
     pub assoc_ty_values: Vec<AssocTyValue>,
+    pub assoc_const_values: Vec<AssocConstValue>,
     pub impl_type: ImplType,
 }
 
@@ -284,6 +306,12 @@ pub struct AssocTyValue {
     pub default: bool,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AssocConstValue {
+    pub name: Identifier,
+    pub value: Const,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Ty {
     Id {
@@ -329,6 +357,7 @@ pub enum Ty {
     },
     Str,
     Never,
+    Error,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -442,24 +471,54 @@ pub enum WhereClause {
     ProjectionEq { projection: ProjectionTy, ty: Ty },
     LifetimeOutlives { a: Lifetime, b: Lifetime },
     TypeOutlives { ty: Ty, lifetime: Lifetime },
+    NotImplemented { trait_ref: TraitRef },
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum DomainGoal {
-    Holds { where_clause: WhereClause },
-    Normalize { projection: ProjectionTy, ty: Ty },
-    TraitRefWellFormed { trait_ref: TraitRef },
-    TyWellFormed { ty: Ty },
-    TyFromEnv { ty: Ty },
-    TraitRefFromEnv { trait_ref: TraitRef },
-    IsLocal { ty: Ty },
-    IsUpstream { ty: Ty },
-    IsFullyVisible { ty: Ty },
-    LocalImplAllowed { trait_ref: TraitRef },
+    Holds {
+        where_clause: WhereClause,
+    },
+    Normalize {
+        projection: ProjectionTy,
+        ty: Ty,
+    },
+    ConstEq {
+        projection: ProjectionTy,
+        value: Const,
+    },
+    TraitRefWellFormed {
+        trait_ref: TraitRef,
+    },
+    TyWellFormed {
+        ty: Ty,
+    },
+    TyFromEnv {
+        ty: Ty,
+    },
+    TraitRefFromEnv {
+        trait_ref: TraitRef,
+    },
+    IsLocal {
+        ty: Ty,
+    },
+    IsUpstream {
+        ty: Ty,
+    },
+    IsFullyVisible {
+        ty: Ty,
+    },
+    LocalImplAllowed {
+        trait_ref: TraitRef,
+    },
     Compatible,
-    DownstreamType { ty: Ty },
+    DownstreamType {
+        ty: Ty,
+    },
     Reveal,
-    ObjectSafe { id: Identifier },
+    ObjectSafe {
+        id: Identifier,
+    },
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
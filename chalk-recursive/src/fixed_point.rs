@@ -3,11 +3,10 @@ use std::hash::Hash;
 use tracing::debug;
 use tracing::{info, instrument};
 
-mod cache;
 mod search_graph;
 mod stack;
 
-pub use cache::Cache;
+pub use chalk_solve::solve::Cache;
 use search_graph::{DepthFirstNumber, SearchGraph};
 use stack::{Stack, StackDepth};
 
@@ -46,24 +45,41 @@ where
     ) -> V;
     fn reached_fixed_point(self, old_value: &V, new_value: &V) -> bool;
     fn error_value(self) -> V;
+    fn overflow_value(self) -> V;
 }
 
 /// The `minimums` struct is used while solving to track whether we encountered
-/// any cycles in the process.
+/// any cycles in the process, and whether we gave up on any goal because the
+/// stack overflowed.
 #[derive(Copy, Clone, Debug)]
 pub(super) struct Minimums {
     positive: DepthFirstNumber,
+    overflow: bool,
 }
 
 impl Minimums {
     pub fn new() -> Self {
         Minimums {
             positive: DepthFirstNumber::MAX,
+            overflow: false,
+        }
+    }
+
+    fn for_new_goal(dfn: DepthFirstNumber) -> Self {
+        Minimums {
+            positive: dfn,
+            overflow: false,
         }
     }
 
     pub fn update_from(&mut self, minimums: Minimums) {
         self.positive = ::std::cmp::min(self.positive, minimums.positive);
+        self.overflow |= minimums.overflow;
+    }
+
+    /// Like `update_from`, but only records whether `minimums` overflowed.
+    pub fn update_overflow_from(&mut self, minimums: Minimums) {
+        self.overflow |= minimums.overflow;
     }
 }
 
@@ -151,6 +167,14 @@ where
                 previous_solution,
             );
             previous_solution
+        } else if self.stack.is_full() {
+            // We have recursed too deeply to keep going; give up on this goal
+            // without recording anything about it. Whether the goals that
+            // depend on it hold depends on how deep we happen to be, so none
+            // of them may be cached either.
+            info!("solve_goal: overflow depth reached");
+            minimums.overflow = true;
+            solver_stuff.overflow_value()
         } else {
             // Otherwise, push the goal onto the stack and create a table.
             // The initial result for this table depends on whether the goal is coinductive.
@@ -174,7 +198,10 @@ where
             // cache now. This is a sort of hack to alleviate the
             // worst of the repeated work that we do during tabling.
             if subgoal_minimums.positive >= dfn {
-                if let Some(cache) = &mut self.cache {
+                if subgoal_minimums.overflow {
+                    debug!("solve_reduced_goal: SCC head encountered, rolling back after overflow");
+                    self.search_graph.rollback_to(dfn);
+                } else if let Some(cache) = &mut self.cache {
                     self.search_graph.move_to_cache(dfn, cache);
                     debug!("solve_reduced_goal: SCC head encountered, moving to cache");
                 } else {
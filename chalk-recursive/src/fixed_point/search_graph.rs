@@ -77,7 +77,7 @@ where
             goal: goal.clone(),
             solution,
             stack_depth: Some(stack_depth),
-            links: Minimums { positive: dfn },
+            links: Minimums::for_new_goal(dfn),
         };
         self.nodes.push(node);
         let previous_index = self.indices.insert(goal.clone(), dfn);
@@ -135,6 +135,12 @@ pub(super) struct Fulfill<'s, I: Interner, Solver: SolveDatabase<I>> {
     /// refuted. In such a case the solution will be either `CannotProve`, or `Err`
     /// in the case where some other goal leads to an error.
     cannot_prove: bool,
+
+    /// Record that some obligation overflowed in the latest round of
+    /// `fulfill`. If we are left with pending obligations at the end and
+    /// learned nothing about the inference variables, the solution will be
+    /// `Overflow` rather than ambiguous.
+    overflowed: bool,
 }
 
 impl<'s, I: Interner, Solver: SolveDatabase<I>> Fulfill<'s, I, Solver> {
@@ -153,6 +159,7 @@ impl<'s, I: Interner, Solver: SolveDatabase<I>> Fulfill<'s, I, Solver> {
             obligations: vec![],
             constraints: FxHashSet::default(),
             cannot_prove: false,
+            overflowed: false,
         };
 
         let ProgramClauseImplication {
@@ -203,6 +210,7 @@ impl<'s, I: Interner, Solver: SolveDatabase<I>> Fulfill<'s, I, Solver> {
             obligations: vec![],
             constraints: FxHashSet::default(),
             cannot_prove: false,
+            overflowed: false,
         };
 
         if let Err(e) = fulfill.push_goal(&canonical_goal.environment, canonical_goal.goal.clone())
@@ -362,7 +370,11 @@ impl<'s, I: Interner, Solver: SolveDatabase<I>> Fulfill<'s, I, Solver> {
         })
     }
 
-    fn refute(&mut self, goal: InEnvironment<Goal<I>>) -> Fallible<NegativeSolution> {
+    fn refute(
+        &mut self,
+        goal: InEnvironment<Goal<I>>,
+        minimums: &mut Minimums,
+    ) -> Fallible<NegativeSolution> {
         let canonicalized = match self
             .infer
             .invert_then_canonicalize(self.solver.interner(), goal)
@@ -378,8 +390,10 @@ impl<'s, I: Interner, Solver: SolveDatabase<I>> Fulfill<'s, I, Solver> {
         // Negate the result
         let (quantified, _) =
             u_canonicalize(&mut self.infer, self.solver.interner(), &canonicalized);
-        let mut minimums = Minimums::new(); // FIXME -- minimums here seems wrong
-        if let Ok(solution) = self.solver.solve_goal(quantified, &mut minimums) {
+        let mut refute_minimums = Minimums::new(); // FIXME -- minimums here seems wrong
+        let result = self.solver.solve_goal(quantified, &mut refute_minimums);
+        minimums.update_overflow_from(refute_minimums);
+        if let Ok(solution) = result {
             if solution.is_unique() {
                 Err(NoSolution)
             } else {
@@ -447,6 +461,7 @@ impl<'s, I: Interner, Solver: SolveDatabase<I>> Fulfill<'s, I, Solver> {
 
         while progress {
             progress = false;
+            self.overflowed = false;
             debug!("start of round, {} obligations", self.obligations.len());
 
             // Take the list of `obligations` to solve this round and replace it
@@ -482,10 +497,14 @@ impl<'s, I: Interner, Solver: SolveDatabase<I>> Fulfill<'s, I, Solver> {
                             }
                         }
 
-                        solution.is_ambig()
+                        if solution.is_overflow() {
+                            self.overflowed = true;
+                        }
+
+                        solution.is_ambig() || solution.is_overflow()
                     }
                     Obligation::Refute(goal) => {
-                        let answer = self.refute(goal.clone())?;
+                        let answer = self.refute(goal.clone(), minimums)?;
                         answer == NegativeSolution::Ambiguous
                     }
                 };
@@ -578,6 +597,13 @@ impl<'s, I: Interner, Solver: SolveDatabase<I>> Fulfill<'s, I, Solver> {
                 }
             }
 
+            if self.overflowed {
+                // Without any guidance to give, the overflow is the most
+                // useful thing we can report.
+                debug!("Some obligation overflowed, returning overflow");
+                return Ok(Solution::Overflow);
+            }
+
             Ok(Solution::Ambig(Guidance::Unknown))
         } else {
             // While we failed to prove the goal, we still learned that
@@ -4,6 +4,7 @@ use crate::UCanonicalGoal;
 use chalk_ir::{interner::Interner, NoSolution};
 use chalk_ir::{Canonical, ConstrainedSubst, Goal, InEnvironment, UCanonical};
 use chalk_ir::{Constraints, Fallible};
+use chalk_solve::solve::simplify_canonical_goal;
 use chalk_solve::{coinductive_goal::IsCoinductive, RustIrDatabase, Solution};
 use std::fmt;
 
@@ -92,7 +93,7 @@ impl<I: Interner> SolverStuff<UCanonicalGoal<I>, Fallible<Solution<I>>> for &dyn
             // in fact we *must* -- otherwise, we sometimes fail to reach a
             // fixed point. See `multiple_ambiguous_cycles` for more.
             match &current_answer {
-                Ok(s) => s.is_ambig(),
+                Ok(s) => s.is_ambig() || s.is_overflow(),
                 Err(_) => false,
             }
         }
@@ -101,6 +102,10 @@ impl<I: Interner> SolverStuff<UCanonicalGoal<I>, Fallible<Solution<I>>> for &dyn
     fn error_value(self) -> Fallible<Solution<I>> {
         Err(NoSolution)
     }
+
+    fn overflow_value(self) -> Fallible<Solution<I>> {
+        Ok(Solution::Overflow)
+    }
 }
 
 impl<'me, I: Interner> SolveDatabase<I> for Solver<'me, I> {
@@ -131,7 +136,8 @@ impl<I: Interner> chalk_solve::Solver<I> for RecursiveSolver<I> {
         program: &dyn RustIrDatabase<I>,
         goal: &UCanonical<InEnvironment<Goal<I>>>,
     ) -> Option<chalk_solve::Solution<I>> {
-        self.ctx.solve_root_goal(goal, program).ok()
+        let goal = simplify_canonical_goal(program.interner(), goal);
+        self.ctx.solve_root_goal(&goal, program).ok()
     }
 
     fn solve_limited(
@@ -141,7 +147,8 @@ impl<I: Interner> chalk_solve::Solver<I> for RecursiveSolver<I> {
         _should_continue: &dyn std::ops::Fn() -> bool,
     ) -> Option<chalk_solve::Solution<I>> {
         // TODO support should_continue in recursive solver
-        self.ctx.solve_root_goal(goal, program).ok()
+        let goal = simplify_canonical_goal(program.interner(), goal);
+        self.ctx.solve_root_goal(&goal, program).ok()
     }
 
     fn solve_multiple(
@@ -9,7 +9,9 @@ use chalk_ir::{
     Canonical, ClausePriority, DomainGoal, Fallible, Floundered, Goal, GoalData, InEnvironment,
     NoSolution, ProgramClause, ProgramClauseData, Substitution, UCanonical,
 };
-use chalk_solve::clauses::program_clauses_that_could_match;
+use chalk_solve::clauses::{
+    dedup_program_clauses, drop_env_only_clauses, program_clauses_that_could_match,
+};
 use chalk_solve::debug_span;
 use chalk_solve::infer::InferenceTable;
 use chalk_solve::{Guidance, RustIrDatabase, Solution};
@@ -149,6 +151,8 @@ trait SolveIterationHelpers<I: Interner>: SolveDatabase<I> {
                 .cloned()
                 .filter(could_match),
         );
+        dedup_program_clauses(&mut clauses);
+        drop_env_only_clauses(db, &goal.environment, &goal.goal, &mut clauses);
 
         let mut cur_solution = None;
         for program_clause in clauses {
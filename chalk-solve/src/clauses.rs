@@ -2,7 +2,8 @@ use self::builder::ClauseBuilder;
 use self::env_elaborator::elaborate_env_clauses;
 use self::program_clauses::ToProgramClauses;
 use crate::goal_builder::GoalBuilder;
-use crate::rust_ir::{Movability, WellKnownTrait};
+use crate::infer::InferenceTable;
+use crate::rust_ir::{self, Movability, WellKnownTrait};
 use crate::split::Split;
 use crate::RustIrDatabase;
 use chalk_ir::cast::{Cast, Caster};
@@ -16,11 +17,17 @@ use tracing::{debug, instrument};
 
 pub mod builder;
 mod builtin_traits;
+pub mod cycles;
 mod dyn_ty;
 mod env_elaborator;
 mod generalize;
+mod goal_walk;
 pub mod program_clauses;
+pub mod reachable;
+#[cfg(feature = "specialization")]
+mod specialization;
 mod super_traits;
+pub mod validate;
 
 // yields the types "contained" in `app_ty`
 fn constituent_types<I: Interner>(db: &dyn RustIrDatabase<I>, ty: &TyKind<I>) -> Vec<Ty<I>> {
@@ -86,13 +93,76 @@ fn constituent_types<I: Interner>(db: &dyn RustIrDatabase<I>, ty: &TyKind<I>) ->
     }
 }
 
+/// Returns true if the explicit impls of the auto trait `auto_trait_id`
+/// for types with the same head as `ty` replace its default impl. That is
+/// the case if any of them is negative, or is a blanket impl like
+/// `impl<T> Send for MyList<T>` that covers every instance of the head.
+/// A specific impl like `impl Send for MyList<Foo>` only takes over for
+/// `MyList<Foo>` itself, so the default impl is kept for the others.
+/// Databases that don't list the impls only tell us whether there are
+/// any, and then every explicit impl replaces the default impl.
+pub fn auto_trait_impl_suppressed<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    auto_trait_id: TraitId<I>,
+    ty: &TyKind<I>,
+) -> bool {
+    let interner = db.interner();
+    let impl_ids = db.impls_provided_for(auto_trait_id, ty);
+    if impl_ids.is_empty() {
+        return db.impl_provided_for(auto_trait_id, ty);
+    }
+    impl_ids.into_iter().any(|impl_id| {
+        let impl_datum = db.impl_datum(impl_id);
+        let self_ty = impl_datum
+            .binders
+            .skip_binders()
+            .trait_ref
+            .self_type_parameter(interner);
+        !impl_datum.is_positive() || is_blanket_self_ty(interner, &self_ty)
+    })
+}
+
+/// Checks whether all the type and const parameters of `ty` are distinct
+/// bound variables, so that it covers every type with the same head.
+/// Lifetimes are ignored, as impls can't be specialized on them.
+fn is_blanket_self_ty<I: Interner>(interner: I, ty: &Ty<I>) -> bool {
+    let parameters: Vec<Option<BoundVar>> = match ty.kind(interner) {
+        TyKind::Adt(_, substitution)
+        | TyKind::AssociatedType(_, substitution)
+        | TyKind::Tuple(_, substitution)
+        | TyKind::OpaqueType(_, substitution)
+        | TyKind::FnDef(_, substitution)
+        | TyKind::Closure(_, substitution)
+        | TyKind::Generator(_, substitution)
+        | TyKind::GeneratorWitness(_, substitution) => substitution
+            .iter(interner)
+            .filter_map(|parameter| match parameter.data(interner) {
+                GenericArgData::Ty(ty) => Some(ty.bound_var(interner)),
+                GenericArgData::Lifetime(_) => None,
+                GenericArgData::Const(constant) => Some(constant.bound_var(interner)),
+            })
+            .collect(),
+        TyKind::Slice(ty) | TyKind::Raw(_, ty) | TyKind::Ref(_, _, ty) => {
+            vec![ty.bound_var(interner)]
+        }
+        TyKind::Array(ty, constant) => vec![ty.bound_var(interner), constant.bound_var(interner)],
+        _ => vec![],
+    };
+    let mut seen = FxHashSet::default();
+    parameters
+        .into_iter()
+        .all(|bound_var| bound_var.is_some_and(|bound_var| seen.insert(bound_var)))
+}
+
 /// FIXME(#505) update comments for ADTs
 /// For auto-traits, we generate a default rule for every struct,
-/// unless there is a manual impl for that struct given explicitly.
+/// unless a manual impl given explicitly replaces it (see
+/// `auto_trait_impl_suppressed`).
 ///
-/// So, if you have `impl Send for MyList<Foo>`, then we would
+/// So, if you have `impl<T> Send for MyList<T>`, then we would
 /// generate no rule for `MyList` at all -- similarly if you have
-/// `impl !Send for MyList<Foo>`, or `impl<T> Send for MyList<T>`.
+/// `impl !Send for MyList<Foo>`. A specific `impl Send for MyList<Foo>`,
+/// on the other hand, leaves the default rule in place.
 ///
 /// But if you have no rules at all for `Send` / `MyList`, then we
 /// generate an impl based on the field types of `MyList`. For example
@@ -134,10 +204,10 @@ pub fn push_auto_trait_impls<I: Interner>(
         1
     );
 
-    // If there is a `impl AutoTrait for Foo<..>` or `impl !AutoTrait
+    // If there is a `impl<T> AutoTrait for Foo<T>` or `impl !AutoTrait
     // for Foo<..>`, where `Foo` is the adt we're looking at, then
     // we don't generate our own rules.
-    if builder.db.impl_provided_for(auto_trait_id, ty) {
+    if auto_trait_impl_suppressed(builder.db, auto_trait_id, ty) {
         debug!("impl provided");
         return Ok(());
     }
@@ -160,15 +230,16 @@ pub fn push_auto_trait_impls<I: Interner>(
         // auto traits are not implemented for foreign types
         TyKind::Foreign(_) => Ok(()),
 
-        // closures require binders, while the other types do not
-        TyKind::Closure(closure_id, _) => {
-            let binders = builder
+        // closures implement auto traits if their upvars do
+        TyKind::Closure(closure_id, substitution) => {
+            let closure_fn_substitution = builder
                 .db
-                .closure_upvars(*closure_id, &Substitution::empty(interner));
-            builder.push_binders(binders, |builder, upvar_ty| {
-                let conditions = iter::once(mk_ref(upvar_ty));
-                builder.push_clause(consequence, conditions);
-            });
+                .closure_fn_substitution(*closure_id, substitution);
+            let upvars = builder
+                .db
+                .closure_upvars(*closure_id, substitution)
+                .substitute(interner, &closure_fn_substitution);
+            builder.push_clause(consequence, iter::once(mk_ref(upvars)));
             Ok(())
         }
         TyKind::Generator(generator_id, _) => {
@@ -213,6 +284,58 @@ pub fn push_auto_trait_impls<I: Interner>(
     }
 }
 
+/// Pushes the automatic impls of the auto trait `auto_trait_id` for every ADT
+/// in the program (see `push_auto_trait_impls`), for goals like `?T: Send`
+/// whose self type is not known yet. This lets the solver enumerate the ADTs
+/// that implement the trait. Types other than ADTs may implement it as well,
+/// so we also generate
+///
+/// ```notrust
+/// forall<T> {
+///     Implemented(T: Send) :- CannotProve.
+/// }
+/// ```
+///
+/// which keeps the solver from concluding that the ADTs it found are the only
+/// candidates.
+///
+/// Databases opt into this by implementing `RustIrDatabase::adt_ids`; if it
+/// returns no ADTs, the goal flounders.
+fn push_auto_trait_impls_for_all_adts<I: Interner>(
+    builder: &mut ClauseBuilder<'_, I>,
+    auto_trait_id: TraitId<I>,
+) -> Result<(), Floundered> {
+    let interner = builder.interner();
+
+    let adt_ids = builder.db.adt_ids();
+    if adt_ids.is_empty() {
+        return Err(Floundered);
+    }
+    for adt_id in adt_ids {
+        let binders = builder
+            .db
+            .adt_datum(adt_id)
+            .binders
+            .map_ref(|_| PhantomData::<I>);
+        builder.push_binders(binders, |builder, PhantomData| {
+            let ty = TyKind::Adt(adt_id, builder.substitution_in_scope());
+            push_auto_trait_impls(builder, auto_trait_id, &ty)
+        })?;
+    }
+
+    builder.push_bound_ty(|builder, ty| {
+        let trait_ref = TraitRef {
+            trait_id: auto_trait_id,
+            substitution: Substitution::from1(interner, ty),
+        };
+        builder.push_clause(
+            trait_ref,
+            iter::once(GoalData::CannotProve.intern(interner)),
+        );
+    });
+    Ok(())
+}
+
 /// Leak auto traits for opaque types, just like `push_auto_trait_impls` does for structs.
 ///
 /// For example, given the following program:
@@ -349,6 +472,67 @@ pub fn push_auto_trait_impls_generator_witness<I: Interner>(
     })
 }
 
+/// Cheaply checks whether any impl of `trait_id` could apply to `self_ty`,
+/// without running the solver. Only the impl headers are compared using
+/// `could_match`, so a `true` result merely means that proving
+/// `self_ty: Trait` is worth attempting. Parameters of the trait other
+/// than `Self` are left unconstrained.
+///
+/// For auto traits, ADTs without an explicit impl are also counted, since
+/// they get an automatic impl.
+pub fn any_impl_could_match<I: Interner>(
+    trait_id: TraitId<I>,
+    self_ty: &Ty<I>,
+    db: &dyn RustIrDatabase<I>,
+) -> bool {
+    let interner = db.interner();
+    let trait_datum = db.trait_datum(trait_id);
+
+    let variable_kinds = &trait_datum.binders.binders;
+    let substitution = Substitution::from_iter(
+        interner,
+        iter::once(self_ty.clone().cast(interner)).chain(
+            variable_kinds
+                .iter(interner)
+                .enumerate()
+                .skip(1)
+                .map(|(i, kind)| (i, kind).to_generic_arg(interner)),
+        ),
+    );
+    let trait_ref = TraitRef {
+        trait_id,
+        substitution,
+    };
+    let binders = CanonicalVarKinds::from_iter(
+        interner,
+        variable_kinds
+            .iter(interner)
+            .map(|kind| WithKind::new(kind.clone(), UniverseIndex::ROOT)),
+    );
+
+    let impl_could_match = db
+        .impls_for_trait(
+            trait_id,
+            trait_ref.substitution.as_slice(interner),
+            &binders,
+        )
+        .into_iter()
+        .any(|impl_id| {
+            let impl_datum = db.impl_datum(impl_id);
+            impl_datum.is_positive()
+                && impl_datum.binders.skip_binders().trait_ref.could_match(
+                    interner,
+                    db.unification_database(),
+                    &trait_ref,
+                )
+        });
+
+    impl_could_match
+        || trait_datum.is_auto_trait()
+            && matches!(self_ty.kind(interner), TyKind::Adt(..))
+            && !auto_trait_impl_suppressed(db, trait_id, self_ty.kind(interner))
+}
+
 /// Given some goal `goal` that must be proven, along with
 /// its `environment`, figures out the program clauses that apply
 /// to this goal from the Rust program. So for example if the goal
@@ -365,7 +549,7 @@ pub fn program_clauses_for_goal<'db, I: Interner>(
     let clauses_that_could_match =
         program_clauses_that_could_match(db, goal).map(|cl| cl.into_iter())?;
 
-    let clauses: Vec<ProgramClause<I>> = custom_clauses
+    let mut clauses: Vec<ProgramClause<I>> = custom_clauses
         .chain(clauses_that_could_match)
         .chain(
             db.program_clauses_for_env(&goal.canonical.value.environment)
@@ -380,16 +564,70 @@ pub fn program_clauses_for_goal<'db, I: Interner>(
             )
         })
         .collect();
+    dedup_program_clauses(&mut clauses);
 
     debug!(?clauses);
 
     Ok(clauses)
 }
 
+/// Removes duplicate clauses from `clauses`, keeping the first
+/// occurrence of each so that the relative order of the remaining
+/// clauses is preserved. The same clause can reach the solver from
+/// more than one source: for example, the rules for a trait `Foo` are
+/// produced both by the goal `T: Foo` itself and by elaborating an
+/// environment that contains `T: Foo`. Each copy would otherwise be
+/// tried separately, producing redundant strands and answers.
+pub fn dedup_program_clauses<I: Interner>(clauses: &mut Vec<ProgramClause<I>>) {
+    let mut seen = FxHashSet::default();
+    clauses.retain(|clause| seen.insert(clause.clone()));
+}
+
+/// Removes all of `clauses` if each of them can only prove `goal` through an
+/// environment assumption and there are no assumptions to draw on.
+///
+/// Every trait has a rule `Implemented(T: Trait) :- FromEnv(T: Trait)`, but
+/// `FromEnv` goals are ultimately only provable from environment clauses, so
+/// for an `Implemented` goal in an empty environment (and without custom
+/// clauses) those rules can never apply. A goal for a trait without impls
+/// then fails without pursuing any subgoals. Clauses are left alone as soon
+/// as one of them is usable, so that the order in which answers are found
+/// doesn't change.
+pub fn drop_env_only_clauses<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    environment: &Environment<I>,
+    goal: &DomainGoal<I>,
+    clauses: &mut Vec<ProgramClause<I>>,
+) {
+    let interner = db.interner();
+    if !environment.clauses.is_empty(interner)
+        || !db.custom_clauses().is_empty()
+        || !matches!(goal, DomainGoal::Holds(WhereClause::Implemented(_)))
+    {
+        return;
+    }
+    let needs_env = |clause: &ProgramClause<I>| {
+        let implication = clause.data(interner).0.skip_binders();
+        implication.conditions.iter(interner).any(|condition| {
+            matches!(
+                condition.data(interner),
+                GoalData::DomainGoal(DomainGoal::FromEnv(_))
+            )
+        })
+    };
+    if clauses.iter().all(needs_env) {
+        clauses.clear();
+    }
+}
+
 /// Returns a set of program clauses that could possibly match
 /// `goal`. This can be any superset of the correct set, but the
 /// more precise you can make it, the more efficient solving will
 /// be.
+///
+/// Returns `Err(Floundered)` if the goal is too unresolved to enumerate
+/// the clauses for, e.g. `WellFormed(?T)`. Callers should then treat the
+/// goal as ambiguous rather than as having no solution.
 #[instrument(level = "debug", skip(db))]
 pub fn program_clauses_that_could_match<I: Interner>(
     db: &dyn RustIrDatabase<I>,
@@ -397,6 +635,13 @@ pub fn program_clauses_that_could_match<I: Interner>(
 ) -> Result<Vec<ProgramClause<I>>, Floundered> {
     let interner = db.interner();
     let mut clauses: Vec<ProgramClause<I>> = vec![];
+
+    // A negative fact in the environment rules out an `Implemented` goal, no
+    // matter which impls could otherwise prove it.
+    if environment_assumes_not_implemented(db, goal) {
+        return Ok(clauses);
+    }
+
     let builder = &mut ClauseBuilder::new(db, &mut clauses);
 
     let UCanonical {
@@ -416,9 +661,6 @@ pub fn program_clauses_that_could_match<I: Interner>(
             let trait_datum = db.trait_datum(trait_id);
 
             match self_ty.kind(interner) {
-                TyKind::InferenceVar(_, _) => {
-                    panic!("Inference vars not allowed when getting program clauses")
-                }
                 TyKind::Alias(alias) => {
                     // An alias could normalize to anything, including `dyn trait`
                     // or an opaque type, so push a clause that asks for the
@@ -428,9 +670,12 @@ pub fn program_clauses_that_could_match<I: Interner>(
                 }
 
                 _ if self_ty.is_general_var(interner, binders) => {
-                    if trait_datum.is_non_enumerable_trait() || trait_datum.is_auto_trait() {
+                    if trait_datum.is_non_enumerable_trait() {
                         return Err(Floundered);
                     }
+                    if trait_datum.is_auto_trait() {
+                        push_auto_trait_impls_for_all_adts(builder, trait_id)?;
+                    }
                 }
 
                 TyKind::OpaqueType(opaque_ty_id, _) => {
@@ -481,7 +726,7 @@ pub fn program_clauses_that_could_match<I: Interner>(
             // If this is a `Foo: Send` (or any auto-trait), then add
             // the automatic impls for `Foo`.
             let trait_datum = db.trait_datum(trait_id);
-            if trait_datum.is_auto_trait() {
+            if trait_datum.is_auto_trait() && !self_ty.is_general_var(interner, binders) {
                 let generalized = generalize::Generalize::apply(db.interner(), trait_ref.clone());
                 builder.push_binders(generalized, |builder, trait_ref| {
                     let ty = trait_ref.self_type_parameter(interner);
@@ -606,10 +851,6 @@ pub fn program_clauses_that_could_match<I: Interner>(
                 let trait_datum = db.trait_datum(trait_id);
 
                 let self_ty = proj.self_type_parameter(interner);
-                if let TyKind::InferenceVar(_, _) = self_ty.kind(interner) {
-                    panic!("Inference vars not allowed when getting program clauses");
-                }
-
                 // Flounder if the self-type is unknown and the trait is non-enumerable.
                 //
                 // e.g., Normalize(<?X as Iterator>::Item = u32)
@@ -628,7 +869,7 @@ pub fn program_clauses_that_could_match<I: Interner>(
                 push_program_clauses_for_associated_type_values_in_impls_of(
                     builder,
                     environment,
-                    trait_id,
+                    &associated_ty_datum,
                     trait_parameters,
                     binders,
                 );
@@ -645,12 +886,142 @@ pub fn program_clauses_that_could_match<I: Interner>(
             }
             AliasTy::Opaque(_) => (),
         },
+        DomainGoal::ConstEq(ConstEq {
+            projection,
+            value: _,
+        }) => {
+            // Like `Normalize` goals, these derive from the
+            // `AssociatedConstValue`s found in impls of the trait.
+            let associated_const_id = projection.associated_const_id;
+            let trait_id = db.associated_const_data(associated_const_id).trait_id;
+            let trait_datum = db.trait_datum(trait_id);
+
+            let self_ty = projection
+                .substitution
+                .iter(interner)
+                .find_map(|p| p.ty(interner))
+                .unwrap();
+            if self_ty.is_general_var(interner, binders) && trait_datum.is_non_enumerable_trait() {
+                return Err(Floundered);
+            }
+
+            for impl_id in db.impls_for_trait(
+                trait_id,
+                projection.substitution.as_slice(interner),
+                binders,
+            ) {
+                let impl_datum = db.impl_datum(impl_id);
+                if !impl_datum.is_positive() {
+                    continue;
+                }
+                for value in &impl_datum.associated_const_values {
+                    if value.associated_const_id == associated_const_id {
+                        value.to_program_clauses(builder, environment);
+                    }
+                }
+            }
+        }
+        // Given `impl<T> !Send for Foo<T> where WC`, generate:
+        //
+        // forall<T> { NotImplemented(Foo<T>: Send) :- WC. }
+        DomainGoal::Holds(WhereClause::NotImplemented(trait_ref)) => {
+            for impl_id in db.impls_for_trait(
+                trait_ref.trait_id,
+                trait_ref.substitution.as_slice(interner),
+                binders,
+            ) {
+                let impl_datum = db.impl_datum(impl_id);
+                if impl_datum.is_positive() {
+                    continue;
+                }
+                builder.push_binders(
+                    impl_datum.binders.clone(),
+                    |builder,
+                     rust_ir::ImplDatumBound {
+                         trait_ref,
+                         where_clauses,
+                     }| {
+                        builder.push_clause(WhereClause::NotImplemented(trait_ref), where_clauses);
+                    },
+                );
+            }
+        }
         DomainGoal::Compatible | DomainGoal::Reveal => (),
     };
 
     Ok(clauses)
 }
 
+/// Checks whether the environment of `goal`, an `Implemented` goal, contains
+/// a negative fact that covers it. For example, `forall<U> {
+/// NotImplemented(Vec<U>: Send) }` covers `Vec<T>: Send` for any `T`, but
+/// `NotImplemented(Vec<u32>: Send)` doesn't cover `exists<T> { Vec<T>: Send
+/// }`, since `T` could still be something other than `u32`.
+///
+/// Only facts are considered: a clause like `forall<U> {
+/// NotImplemented(Vec<U>: Send) :- U: Clone }` doesn't rule out any goal,
+/// as that would take proving its conditions.
+fn environment_assumes_not_implemented<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    goal: &UCanonical<InEnvironment<DomainGoal<I>>>,
+) -> bool {
+    let interner = db.interner();
+    let trait_id = match &goal.canonical.value.goal {
+        DomainGoal::Holds(WhereClause::Implemented(trait_ref)) => trait_ref.trait_id,
+        _ => return false,
+    };
+    let is_negative_fact = |implication: &ProgramClauseImplication<I>| {
+        implication.conditions.is_empty(interner)
+            && matches!(
+                &implication.consequence,
+                DomainGoal::Holds(WhereClause::NotImplemented(trait_ref))
+                    if trait_ref.trait_id == trait_id
+            )
+    };
+    let environment = &goal.canonical.value.environment;
+    if !environment
+        .clauses
+        .iter(interner)
+        .any(|clause| is_negative_fact(clause.data(interner).0.skip_binders()))
+    {
+        return false;
+    }
+
+    let (mut infer, subst, InEnvironment { environment, goal }) =
+        InferenceTable::from_canonical(interner, goal.universes, goal.canonical.clone());
+    let negative_goal = match goal {
+        DomainGoal::Holds(WhereClause::Implemented(trait_ref)) => {
+            DomainGoal::Holds(WhereClause::NotImplemented(trait_ref))
+        }
+        _ => unreachable!(),
+    };
+    environment.clauses.iter(interner).any(|clause| {
+        let snapshot = infer.snapshot();
+        let implication =
+            infer.instantiate_binders_existentially(interner, clause.data(interner).0.clone());
+        // The fact covers the goal if they unify without constraining any
+        // of the goal's variables.
+        let covers = is_negative_fact(&implication)
+            && infer
+                .relate(
+                    interner,
+                    db.unification_database(),
+                    &environment,
+                    Variance::Invariant,
+                    &implication.consequence,
+                    &negative_goal,
+                )
+                .is_ok_and(|result| result.goals.is_empty())
+            && infer
+                .canonicalize(interner, subst.clone())
+                .quantified
+                .value
+                .is_identity_subst(interner);
+        infer.rollback_to(snapshot);
+        covers
+    })
+}
+
 /// Adds clauses to allow normalizing possible downstream associated type
 /// implementations when in the "compatible" mode. Example clauses:
 ///
@@ -726,14 +1097,19 @@ fn push_clauses_for_compatible_normalize<I: Interner>(
 fn push_program_clauses_for_associated_type_values_in_impls_of<I: Interner>(
     builder: &mut ClauseBuilder<'_, I>,
     environment: &Environment<I>,
-    trait_id: TraitId<I>,
+    associated_ty_datum: &rust_ir::AssociatedTyDatum<I>,
     trait_parameters: &[GenericArg<I>],
     binders: &CanonicalVarKinds<I>,
 ) {
-    for impl_id in builder
-        .db
-        .impls_for_trait(trait_id, trait_parameters, binders)
-    {
+    let impl_ids =
+        builder
+            .db
+            .impls_for_trait(associated_ty_datum.trait_id, trait_parameters, binders);
+    #[cfg(feature = "specialization")]
+    let impl_ids =
+        specialization::remove_overridden_impls(builder.db, impl_ids, trait_parameters, binders);
+
+    for impl_id in impl_ids {
         let impl_datum = builder.db.impl_datum(impl_id);
         if !impl_datum.is_positive() {
             continue;
@@ -746,7 +1122,87 @@ fn push_program_clauses_for_associated_type_values_in_impls_of<I: Interner>(
             debug!(?atv_id, ?atv);
             atv.to_program_clauses(builder, environment);
         }
+
+        if !impl_datum.provides_associated_ty(builder.db, associated_ty_datum.id) {
+            push_default_associated_ty_value_clause(builder, &impl_datum, associated_ty_datum);
+        }
+    }
+}
+
+/// Given the trait and impl
+///
+/// ```notrust
+/// trait Iterator { type Item = u32; }
+/// impl<T> Iterator for Vec<T> where T: Clone { }
+/// ```
+///
+/// where the impl doesn't give a value for `Item`, we generate
+///
+/// ```notrust
+/// forall<T> {
+///     Normalize(<Vec<T> as Iterator>::Item -> u32) :- Implemented(T: Clone).
+/// }
+/// ```
+///
+/// along with the where clauses of the `type` declaration, like for
+/// associated type values given in impls. Nothing is generated if the
+/// trait has no default.
+fn push_default_associated_ty_value_clause<I: Interner>(
+    builder: &mut ClauseBuilder<'_, I>,
+    impl_datum: &rust_ir::ImplDatum<I>,
+    associated_ty_datum: &rust_ir::AssociatedTyDatum<I>,
+) {
+    if associated_ty_datum.binders.skip_binders().default.is_none() {
+        return;
     }
+    let interner = builder.interner();
+    builder.push_binders(impl_datum.binders.clone(), |builder, impl_bound| {
+        // The parameters of the associated type itself, e.g. `'a` in
+        // `type IntoIter<'a> = Iter<'a, Self>;`.
+        let (_, assoc_ty_kinds) = builder.db.split_associated_ty_parameters(
+            associated_ty_datum.binders.binders.as_slice(interner),
+            associated_ty_datum,
+        );
+        let assoc_ty_binders = Binders::new(
+            VariableKinds::from_iter(interner, assoc_ty_kinds.iter().cloned()),
+            PhantomData::<I>,
+        );
+        builder.push_binders(assoc_ty_binders, |builder, PhantomData| {
+            let placeholders_in_scope = builder.placeholders_in_scope();
+            let assoc_ty_params =
+                &placeholders_in_scope[placeholders_in_scope.len() - assoc_ty_kinds.len()..];
+            let substitution = Substitution::from_iter(
+                interner,
+                assoc_ty_params
+                    .iter()
+                    .chain(impl_bound.trait_ref.substitution.iter(interner))
+                    .cloned(),
+            );
+            let rust_ir::AssociatedTyDatumBound {
+                where_clauses,
+                default,
+                ..
+            } = associated_ty_datum
+                .binders
+                .clone()
+                .substitute(interner, &substitution);
+
+            builder.push_clause(
+                Normalize {
+                    alias: AliasTy::Projection(ProjectionTy {
+                        associated_ty_id: associated_ty_datum.id,
+                        substitution,
+                    }),
+                    ty: default.unwrap(),
+                },
+                impl_bound
+                    .where_clauses
+                    .into_iter()
+                    .chain(where_clauses)
+                    .casted::<Goal<I>>(interner),
+            );
+        });
+    });
 }
 
 fn push_alias_implemented_clause<I: Interner>(
@@ -866,9 +1322,9 @@ fn match_ty<I: Interner>(
 ) -> Result<(), Floundered> {
     let interner = builder.interner();
     Ok(match ty.kind(interner) {
-        TyKind::InferenceVar(_, _) => {
-            panic!("Inference vars not allowed when getting program clauses")
-        }
+        // As for unbound variables, there is no way to tell which clauses
+        // apply until the variable is resolved.
+        TyKind::InferenceVar(_, _) => return Err(Floundered),
         TyKind::Adt(adt_id, _) => builder
             .db
             .adt_datum(*adt_id)
@@ -877,7 +1333,6 @@ fn match_ty<I: Interner>(
             .db
             .opaque_ty_data(*opaque_ty_id)
             .to_program_clauses(builder, environment),
-        TyKind::Error => {}
         TyKind::AssociatedType(type_id, _) => builder
             .db
             .associated_ty_data(*type_id)
@@ -894,6 +1349,12 @@ fn match_ty<I: Interner>(
             // These have no substitutions, so they are trivially WF
             builder.push_fact(WellFormed::Ty(ty.clone()));
         }
+        TyKind::Error => {
+            // The error type stands in for a type that failed to lower; the
+            // failure has already been reported, so don't let it make
+            // everything around it ill-formed too.
+            builder.push_fact(WellFormed::Ty(ty.clone()));
+        }
         TyKind::Raw(mutbl, _) => {
             // forall<T> WF(*const T) :- WF(T);
             builder.push_bound_ty(|builder, ty| {
@@ -910,15 +1371,34 @@ fn match_ty<I: Interner>(
                     let ref_ty = TyKind::Ref(*mutbl, lifetime.clone(), ty.clone())
                         .intern(builder.interner());
                     builder.push_clause(
-                        WellFormed::Ty(ref_ty),
+                        WellFormed::Ty(ref_ty.clone()),
                         [
                             DomainGoal::WellFormed(WellFormed::Ty(ty.clone())),
                             DomainGoal::Holds(WhereClause::TypeOutlives(TypeOutlives {
-                                ty,
+                                ty: ty.clone(),
                                 lifetime,
                             })),
                         ],
                     );
+
+                    // References are fundamental, so for the orphan rules they
+                    // are local (or upstream) exactly when their referent is:
+                    //
+                    // forall<'a, T> IsLocal(&'a T) :- IsLocal(T)
+                    // forall<'a, T> IsUpstream(&'a T) :- IsUpstream(T)
+                    // forall<'a, T> DownstreamType(&'a T) :- DownstreamType(T)
+                    builder.push_clause(
+                        DomainGoal::IsLocal(ref_ty.clone()),
+                        Some(DomainGoal::IsLocal(ty.clone())),
+                    );
+                    builder.push_clause(
+                        DomainGoal::IsUpstream(ref_ty.clone()),
+                        Some(DomainGoal::IsUpstream(ty.clone())),
+                    );
+                    builder.push_clause(
+                        DomainGoal::DownstreamType(ref_ty),
+                        Some(DomainGoal::DownstreamType(ty)),
+                    );
                 })
             });
         }
@@ -1047,12 +1527,16 @@ fn match_ty<I: Interner>(
         }
         TyKind::BoundVar(_) => return Err(Floundered),
         TyKind::Dyn(dyn_ty) => {
+            // WF(dyn Trait<Assoc = U> + AutoTrait) :-
+            //     ObjectSafe(Trait), WF(Self: Trait), WF(U)
+            //
+            // and only if every associated type of `Trait` is bound.
+            //
             // FIXME(#203)
-            // - Object safety? (not needed with RFC 2027)
             // - Implied bounds
             // - Bounds on the associated types
-            // - Checking that all associated types are specified, including
-            //   those on supertraits.
+            // - Checking that the associated types of supertraits are
+            //   specified as well.
             // - For trait objects with GATs, if we allow them in the future,
             //   check that the bounds are fully general (
             //   `dyn for<'a> StreamingIterator<Item<'a> = &'a ()>` is OK,
@@ -1060,27 +1544,64 @@ fn match_ty<I: Interner>(
             let generalized_ty =
                 generalize::Generalize::apply(builder.db.interner(), dyn_ty.clone());
             builder.push_binders(generalized_ty, |builder, dyn_ty| {
+                let db = builder.db;
+                let ty = TyKind::Dyn(dyn_ty.clone()).intern(interner);
                 let bounds = dyn_ty
                     .bounds
                     .clone()
                     .substitute(interner, &[ty.clone().cast::<GenericArg<I>>(interner)]);
 
+                let bound_assoc_tys: Vec<_> = bounds
+                    .iter(interner)
+                    .filter_map(|bound| match bound.skip_binders() {
+                        WhereClause::AliasEq(AliasEq {
+                            alias: AliasTy::Projection(projection),
+                            ..
+                        }) => Some(projection.associated_ty_id),
+                        _ => None,
+                    })
+                    .collect();
+                let all_assoc_tys_bound =
+                    bounds
+                        .iter(interner)
+                        .all(|bound| match bound.skip_binders() {
+                            WhereClause::Implemented(trait_ref) => db
+                                .trait_datum(trait_ref.trait_id)
+                                .associated_ty_ids
+                                .iter()
+                                .all(|id| bound_assoc_tys.contains(id)),
+                            _ => true,
+                        });
+                if !all_assoc_tys_bound {
+                    return;
+                }
+
                 let mut wf_goals = Vec::new();
 
                 wf_goals.extend(bounds.iter(interner).flat_map(|bound| {
                     bound.map_ref(|bound| -> Vec<_> {
                         match bound {
                             WhereClause::Implemented(trait_ref) => {
-                                vec![DomainGoal::WellFormed(WellFormed::Trait(trait_ref.clone()))]
+                                let trait_id = trait_ref.trait_id;
+                                let wf_trait =
+                                    DomainGoal::WellFormed(WellFormed::Trait(trait_ref.clone()));
+                                if db.trait_datum(trait_id).is_auto_trait() {
+                                    vec![wf_trait]
+                                } else {
+                                    vec![DomainGoal::ObjectSafe(trait_id), wf_trait]
+                                }
                             }
-                            WhereClause::AliasEq(_)
-                            | WhereClause::LifetimeOutlives(_)
-                            | WhereClause::TypeOutlives(_) => vec![],
+                            WhereClause::AliasEq(AliasEq { ty, .. }) => {
+                                vec![DomainGoal::WellFormed(WellFormed::Ty(ty.clone()))]
+                            }
+                            WhereClause::LifetimeOutlives(_)
+                            | WhereClause::TypeOutlives(_)
+                            | WhereClause::NotImplemented(_) => vec![],
                         }
                     })
                 }));
 
-                builder.push_clause(WellFormed::Ty(ty.clone()), wf_goals);
+                builder.push_clause(WellFormed::Ty(ty), wf_goals);
             });
         }
     })
@@ -1100,31 +1621,40 @@ fn match_alias_ty<I: Interner>(
     }
 }
 
+/// Computes the closure of the clauses in `environment` under implied-bound
+/// elaboration (see `elaborate_env_clauses`).
+///
+/// The fixpoint below always terminates, even for cyclic traits like
+/// `trait A where Self: B { } trait B where Self: A { }`: elaboration only
+/// ever produces the (generic) program clauses of traits, associated types
+/// and types that are named somewhere in the clauses seen so far. There are
+/// finitely many of those, and `seen` deduplicates them, so each round
+/// either adds a new clause or ends the loop.
+///
+/// The result is ordered deterministically: first the environment's own
+/// clauses, then the clauses of each elaboration round in the order they
+/// were generated. Keeping that order (rather than sorting) means it only
+/// depends on the environment and the program, and it matches the order
+/// `dedup_program_clauses` preserves for the other clause sources.
 #[instrument(level = "debug", skip(db))]
 pub fn program_clauses_for_env<'db, I: Interner>(
     db: &'db dyn RustIrDatabase<I>,
     environment: &Environment<I>,
 ) -> ProgramClauses<I> {
-    let mut last_round = environment
-        .clauses
-        .as_slice(db.interner())
-        .iter()
-        .cloned()
-        .collect::<FxHashSet<_>>();
+    let mut last_round = environment.clauses.as_slice(db.interner()).to_vec();
+    dedup_program_clauses(&mut last_round);
+    let mut seen = last_round.iter().cloned().collect::<FxHashSet<_>>();
     let mut closure = last_round.clone();
-    let mut next_round = FxHashSet::default();
+    let mut next_round = vec![];
     while !last_round.is_empty() {
-        elaborate_env_clauses(
-            db,
-            &last_round.drain().collect::<Vec<_>>(),
-            &mut next_round,
-            environment,
-        );
+        elaborate_env_clauses(db, &last_round, &mut next_round, environment);
+        last_round.clear();
         last_round.extend(
             next_round
-                .drain()
-                .filter(|clause| closure.insert(clause.clone())),
+                .drain(..)
+                .filter(|clause| seen.insert(clause.clone())),
         );
+        closure.extend(last_round.iter().cloned());
     }
 
     ProgramClauses::from_iter(db.interner(), closure)
@@ -1,5 +1,7 @@
 use std::marker::PhantomData;
 
+#[cfg(feature = "validate-clauses")]
+use super::validate::{validate_bound_vars, validate_generic_arg_kinds};
 use crate::cast::{Cast, CastTo};
 use crate::RustIrDatabase;
 use chalk_ir::fold::{Fold, Shift};
@@ -105,6 +107,22 @@ impl<'me, I: Interner> ClauseBuilder<'me, I> {
         );
 
         debug!("pushed clause {:?}", self.clauses.last());
+        #[cfg(feature = "validate-clauses")]
+        {
+            let clause = self.clauses.last().unwrap();
+            assert_eq!(
+                validate_bound_vars(interner, clause),
+                Ok(()),
+                "malformed clause {:?}",
+                clause
+            );
+            assert_eq!(
+                validate_generic_arg_kinds(self.db, clause),
+                Ok(()),
+                "malformed clause {:?}",
+                clause
+            );
+        }
     }
 
     /// Accesses the placeholders for the current list of parameters in scope.
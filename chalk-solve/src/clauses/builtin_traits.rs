@@ -1,6 +1,6 @@
 use super::{builder::ClauseBuilder, generalize};
 use crate::{CanonicalVarKinds, Interner, RustIrDatabase, TraitRef, WellKnownTrait};
-use chalk_ir::{Floundered, Substitution, Ty};
+use chalk_ir::{Floundered, Substitution, Ty, TyKind};
 
 mod clone;
 mod copy;
@@ -52,6 +52,12 @@ pub fn add_builtin_program_clauses<I: Interner>(
             WellKnownTrait::Generator => {
                 generator::add_generator_program_clauses(db, builder, self_ty)?;
             }
+            // Tuple is implemented for tuples of every arity, including `()`
+            WellKnownTrait::Tuple => {
+                if let TyKind::Tuple(..) = ty {
+                    builder.push_fact(trait_ref);
+                }
+            }
         }
         Ok(())
     })
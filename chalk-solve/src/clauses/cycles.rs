@@ -0,0 +1,245 @@
+//! Detects goals that can only be proven through a strictly unproductive
+//! cycle, e.g. `X: A` given nothing but `impl A for X where X: A`.
+//!
+//! The solvers treat such a cycle like any other inductive cycle and
+//! eventually give up on the goal; `check_unproductive_cycles` instead
+//! reports the goal and the clauses that lead back to it. Like
+//! `reachable_clauses`, it follows the domain goals in the conditions of every
+//! clause that could match, treating each condition as an independent goal.
+
+use super::goal_walk::{
+    canonicalize, is_too_large, push_domain_goals, GoalWalk, UCanonicalDomainGoal,
+};
+use super::program_clauses_for_goal;
+use crate::coinductive_goal::IsCoinductive;
+use crate::infer::InferenceTable;
+use crate::RustIrDatabase;
+use chalk_ir::cast::Cast;
+use chalk_ir::interner::Interner;
+use chalk_ir::*;
+use rustc_hash::FxHashMap;
+use std::fmt;
+use std::mem;
+
+/// A goal whose proof attempts reappear as the same goal, without any new
+/// bindings, through an inductive cycle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CycleError<I: Interner> {
+    /// The goal that reappears.
+    pub goal: UCanonical<InEnvironment<DomainGoal<I>>>,
+    /// The clauses tried on the way from `goal` back to itself, in order.
+    pub clauses: Vec<ProgramClause<I>>,
+}
+
+impl<I: Interner> fmt::Display for CycleError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unproductive cycle while proving `{:?}` through {} clause(s)",
+            self.goal.canonical.value.goal,
+            self.clauses.len()
+        )
+    }
+}
+
+impl<I: Interner> std::error::Error for CycleError<I> {}
+
+/// Checks that no domain goal of `goal` can only be proven through a strictly
+/// unproductive cycle.
+///
+/// A goal is reported if none of the clauses that could match it can be used
+/// to prove it, and at least one of them can't because it has a condition
+/// that is reported itself, or that is the same goal again through a cycle
+/// that isn't entirely coinductive (such cycles, e.g. for auto traits, are
+/// productive). A clause is assumed to be usable if it is a fact or binds
+/// some of the goal's variables. Goals that flounder or grow larger than
+/// `MAX_GOAL_SIZE` are assumed to be provable, and goals under a negation are
+/// not checked.
+///
+/// Each goal is checked once. The result for a goal that runs into a cycle
+/// through a goal further down the stack is only reused while that goal is
+/// being checked with the same clause, so the cycle reported for it may have
+/// been found through another path.
+pub fn check_unproductive_cycles<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    goal: &UCanonical<InEnvironment<Goal<I>>>,
+) -> Result<(), CycleError<I>> {
+    let interner = db.interner();
+    let (mut infer, _, InEnvironment { environment, goal }) =
+        InferenceTable::from_canonical(interner, goal.universes, goal.canonical.clone());
+    let mut domain_goals = vec![];
+    push_domain_goals(
+        interner,
+        &mut infer,
+        &environment,
+        &goal,
+        GoalWalk::Precise,
+        &mut domain_goals,
+    );
+
+    let mut checker = CycleChecker {
+        db,
+        stack: vec![],
+        results: FxHashMap::default(),
+        provisional: FxHashMap::default(),
+        min_depth: usize::MAX,
+    };
+    for domain_goal in &domain_goals {
+        checker.check(domain_goal)?;
+    }
+    Ok(())
+}
+
+/// Whether a goal that isn't part of an unproductive cycle could be proven.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Outcome {
+    Provable,
+    Unprovable,
+}
+
+type CheckResult<I> = Result<Outcome, CycleError<I>>;
+
+struct CycleChecker<'a, I: Interner> {
+    db: &'a dyn RustIrDatabase<I>,
+    /// The goals currently being checked, each with the clause being tried.
+    stack: Vec<(UCanonicalDomainGoal<I>, ProgramClause<I>)>,
+    /// The results for goals that don't depend on the goals on the stack.
+    results: FxHashMap<UCanonicalDomainGoal<I>, CheckResult<I>>,
+    /// The results for goals that ran into a cycle through a goal on the
+    /// stack, with the depth of the lowest such goal. They are dropped once
+    /// that goal moves on to its next clause.
+    provisional: FxHashMap<UCanonicalDomainGoal<I>, (CheckResult<I>, usize)>,
+    /// The depth of the lowest goal on the stack that the goal being checked
+    /// has run into a cycle through so far.
+    min_depth: usize,
+}
+
+impl<I: Interner> CycleChecker<'_, I> {
+    fn check(&mut self, goal: &UCanonicalDomainGoal<I>) -> CheckResult<I> {
+        if let Some(result) = self.results.get(goal) {
+            return result.clone();
+        }
+        if let Some((result, depth)) = self.provisional.get(goal) {
+            self.min_depth = self.min_depth.min(*depth);
+            return result.clone();
+        }
+        if let Some(depth) = self.stack.iter().position(|(entry, _)| entry == goal) {
+            self.min_depth = self.min_depth.min(depth);
+            return self.check_cycle(goal, depth);
+        }
+
+        let depth = self.stack.len();
+        let outer_min_depth = mem::replace(&mut self.min_depth, usize::MAX);
+        let result = self.check_clauses(goal);
+        if self.min_depth >= depth {
+            self.results.insert(goal.clone(), result.clone());
+        } else {
+            self.provisional
+                .insert(goal.clone(), (result.clone(), self.min_depth));
+        }
+        self.min_depth = self.min_depth.min(outer_min_depth);
+        result
+    }
+
+    /// `goal` reappeared at `depth` of the stack.
+    fn check_cycle(&self, goal: &UCanonicalDomainGoal<I>, depth: usize) -> CheckResult<I> {
+        let interner = self.db.interner();
+        let cycle = &self.stack[depth..];
+        let coinductive = cycle.iter().all(|(entry, _)| {
+            let goal: Goal<I> = entry.canonical.value.goal.clone().cast(interner);
+            goal.is_coinductive(self.db)
+        });
+        if coinductive {
+            Ok(Outcome::Provable)
+        } else {
+            Err(CycleError {
+                goal: goal.clone(),
+                clauses: cycle.iter().map(|(_, clause)| clause.clone()).collect(),
+            })
+        }
+    }
+
+    fn check_clauses(&mut self, goal: &UCanonicalDomainGoal<I>) -> CheckResult<I> {
+        let db = self.db;
+        let interner = db.interner();
+        let (
+            mut infer,
+            _,
+            InEnvironment {
+                environment,
+                goal: domain_goal,
+            },
+        ) = InferenceTable::from_canonical(interner, goal.universes, goal.canonical.clone());
+        if is_too_large(interner, &mut infer, &domain_goal) {
+            return Ok(Outcome::Provable);
+        }
+        let clauses = match program_clauses_for_goal(db, goal) {
+            Ok(clauses) => clauses,
+            Err(Floundered) => return Ok(Outcome::Provable),
+        };
+
+        let mut first_error = None;
+        for clause in clauses {
+            let snapshot = infer.snapshot();
+            let implication =
+                infer.instantiate_binders_existentially(interner, clause.data(interner).0.clone());
+            let unified = infer.relate(
+                interner,
+                db.unification_database(),
+                &environment,
+                Variance::Invariant,
+                &implication.consequence,
+                &domain_goal,
+            );
+            let result = if unified.is_err() {
+                None
+            } else if implication.conditions.is_empty(interner)
+                || canonicalize(interner, &mut infer, &environment, &domain_goal) != *goal
+            {
+                // A fact, or a clause that makes progress on the goal by
+                // binding some of its variables.
+                Some(Ok(Outcome::Provable))
+            } else {
+                let mut conditions = vec![];
+                for condition in implication.conditions.iter(interner) {
+                    push_domain_goals(
+                        interner,
+                        &mut infer,
+                        &environment,
+                        condition,
+                        GoalWalk::Precise,
+                        &mut conditions,
+                    );
+                }
+                self.stack.push((goal.clone(), clause.clone()));
+                let result = self.check_conditions(&conditions);
+                self.stack.pop();
+                let depth = self.stack.len();
+                self.provisional
+                    .retain(|_, (_, min_depth)| *min_depth < depth);
+                Some(result)
+            };
+            infer.rollback_to(snapshot);
+
+            match result {
+                Some(Ok(Outcome::Provable)) => return Ok(Outcome::Provable),
+                Some(Err(error)) => {
+                    first_error.get_or_insert(error);
+                }
+                Some(Ok(Outcome::Unprovable)) | None => (),
+            }
+        }
+        first_error.map_or(Ok(Outcome::Unprovable), Err)
+    }
+
+    /// Checks the conditions of a clause up to the first one that can't be
+    /// proven.
+    fn check_conditions(&mut self, conditions: &[UCanonicalDomainGoal<I>]) -> CheckResult<I> {
+        for condition in conditions {
+            if self.check(condition)? == Outcome::Unprovable {
+                return Ok(Outcome::Unprovable);
+            }
+        }
+        Ok(Outcome::Provable)
+    }
+}
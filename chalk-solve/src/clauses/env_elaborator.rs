@@ -10,7 +10,6 @@ use crate::{debug_span, TyKind};
 use chalk_ir::interner::Interner;
 use chalk_ir::visit::{Visit, Visitor};
 use chalk_ir::{DebruijnIndex, Environment};
-use rustc_hash::FxHashSet;
 use std::ops::ControlFlow;
 use tracing::instrument;
 
@@ -23,7 +22,7 @@ use tracing::instrument;
 pub(super) fn elaborate_env_clauses<I: Interner>(
     db: &dyn RustIrDatabase<I>,
     in_clauses: &[ProgramClause<I>],
-    out: &mut FxHashSet<ProgramClause<I>>,
+    out: &mut Vec<ProgramClause<I>>,
     environment: &Environment<I>,
 ) {
     let mut this_round = vec![];
@@ -0,0 +1,97 @@
+//! Helpers shared by the analyses in `reachable` and `cycles`, which both
+//! follow the domain goals in the conditions of program clauses.
+
+use crate::infer::InferenceTable;
+use crate::solve::truncate;
+use chalk_ir::interner::Interner;
+use chalk_ir::*;
+
+pub(super) type UCanonicalDomainGoal<I> = UCanonical<InEnvironment<DomainGoal<I>>>;
+
+/// Goals with types larger than this are not followed further, which keeps
+/// the walk finite for impls like `impl<T> Foo for T where Box<T>: Foo`.
+pub(super) const MAX_GOAL_SIZE: usize = 30;
+
+/// Whether `goal` is too large to be followed (see `MAX_GOAL_SIZE`).
+pub(super) fn is_too_large<I: Interner>(
+    interner: I,
+    infer: &mut InferenceTable<I>,
+    goal: &DomainGoal<I>,
+) -> bool {
+    truncate::needs_truncation(interner, infer, MAX_GOAL_SIZE, goal)
+}
+
+/// How `push_domain_goals` treats quantifiers, implications and negations.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum GoalWalk {
+    /// Every goal that could be looked at while solving: `forall` is treated
+    /// like `exists`, the hypotheses of implications are ignored and goals
+    /// under a negation are followed.
+    OverApproximate,
+    /// The goals that must hold: `forall` introduces placeholders, the
+    /// hypotheses of implications are added to the environment and goals
+    /// under a negation are skipped.
+    Precise,
+}
+
+pub(super) fn canonicalize<I: Interner>(
+    interner: I,
+    infer: &mut InferenceTable<I>,
+    environment: &Environment<I>,
+    domain_goal: &DomainGoal<I>,
+) -> UCanonicalDomainGoal<I> {
+    let canonical = infer
+        .canonicalize(
+            interner,
+            InEnvironment::new(environment, domain_goal.clone()),
+        )
+        .quantified;
+    InferenceTable::u_canonicalize(interner, &canonical).quantified
+}
+
+/// Pushes the domain goals that must be proven for `goal` onto `out`,
+/// canonicalized.
+pub(super) fn push_domain_goals<I: Interner>(
+    interner: I,
+    infer: &mut InferenceTable<I>,
+    environment: &Environment<I>,
+    goal: &Goal<I>,
+    walk: GoalWalk,
+    out: &mut Vec<UCanonicalDomainGoal<I>>,
+) {
+    match goal.data(interner) {
+        GoalData::Quantified(QuantifierKind::ForAll, subgoal) if walk == GoalWalk::Precise => {
+            let subgoal = infer.instantiate_binders_universally(interner, subgoal.clone());
+            push_domain_goals(interner, infer, environment, &subgoal, walk, out);
+        }
+        GoalData::Quantified(_, subgoal) => {
+            let subgoal = infer.instantiate_binders_existentially(interner, subgoal.clone());
+            push_domain_goals(interner, infer, environment, &subgoal, walk, out);
+        }
+        GoalData::Implies(clauses, subgoal) => match walk {
+            GoalWalk::OverApproximate => {
+                push_domain_goals(interner, infer, environment, subgoal, walk, out)
+            }
+            GoalWalk::Precise => {
+                let environment = environment
+                    .clone()
+                    .add_clauses(interner, clauses.iter(interner).cloned());
+                push_domain_goals(interner, infer, &environment, subgoal, walk, out);
+            }
+        },
+        GoalData::Not(subgoal) => {
+            if walk == GoalWalk::OverApproximate {
+                push_domain_goals(interner, infer, environment, subgoal, walk, out);
+            }
+        }
+        GoalData::All(goals) => {
+            for subgoal in goals.iter(interner) {
+                push_domain_goals(interner, infer, environment, subgoal, walk, out);
+            }
+        }
+        GoalData::DomainGoal(domain_goal) => {
+            out.push(canonicalize(interner, infer, environment, domain_goal));
+        }
+        GoalData::EqGoal(_) | GoalData::SubtypeGoal(_) | GoalData::CannotProve => (),
+    }
+}
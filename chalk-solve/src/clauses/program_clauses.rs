@@ -49,6 +49,39 @@ impl<I: Interner> ToProgramClauses<I> for ImplDatum<I> {
     }
 }
 
+impl<I: Interner> ToProgramClauses<I> for AssociatedConstValue<I> {
+    /// Given `impl<T: Clone> Foo for Vec<T> { const N = 3; }`, generate:
+    ///
+    /// ```notrust
+    /// -- Rule ConstEq-From-Impl
+    /// forall<T> {
+    ///     ConstEq(<Vec<T> as Foo>::N = 3) :- Implemented(T: Clone).
+    /// }
+    /// ```
+    fn to_program_clauses(
+        &self,
+        builder: &mut ClauseBuilder<'_, I>,
+        _environment: &Environment<I>,
+    ) {
+        let impl_datum = builder.db.impl_datum(self.impl_id);
+
+        // The value is bound by the same parameters as the impl itself.
+        let binders = impl_datum.binders.map_ref(|bound| {
+            let const_eq = ConstEq {
+                projection: ConstProjection {
+                    associated_const_id: self.associated_const_id,
+                    substitution: bound.trait_ref.substitution.clone(),
+                },
+                value: self.value.skip_binders().clone(),
+            };
+            (const_eq, bound.where_clauses.clone())
+        });
+        builder.push_binders(binders, |builder, (const_eq, where_clauses)| {
+            builder.push_clause(const_eq, where_clauses);
+        });
+    }
+}
+
 impl<I: Interner> ToProgramClauses<I> for AssociatedTyValue<I> {
     /// Given the following trait:
     ///
@@ -206,7 +239,9 @@ impl<I: Interner> ToProgramClauses<I> for OpaqueTyDatum<I> {
                         )
                     }
                     // FIXME: Associated item bindings are just taken as facts (?)
-                    WhereClause::AliasEq(_) => builder.push_fact(bound),
+                    WhereClause::AliasEq(_) | WhereClause::NotImplemented(_) => {
+                        builder.push_fact(bound)
+                    }
                     WhereClause::LifetimeOutlives(..) => {}
                     WhereClause::TypeOutlives(..) => {}
                 });
@@ -691,6 +726,19 @@ impl<I: Interner> ToProgramClauses<I> for TraitDatum<I> {
                 }
             }
 
+            // A trait alias `trait Foo = Bar + Baz` has no impls; instead we
+            // create the rule
+            //
+            // ```
+            // Implemented(T: Foo) :- Implemented(T: Bar), Implemented(T: Baz)
+            // ```
+            if self.flags.alias {
+                builder.push_clause(
+                    trait_ref.clone(),
+                    where_clauses.iter().cloned().casted::<Goal<_>>(interner),
+                );
+            }
+
             // Reverse implied bound rules: given (e.g.) `trait Foo: Bar + Baz`,
             // we create rules like:
             //
@@ -804,6 +852,7 @@ impl<I: Interner> ToProgramClauses<I> for AssociatedTyDatum<I> {
              AssociatedTyDatumBound {
                  where_clauses,
                  bounds,
+                 ..
              }| {
                 let substitution = builder.substitution_in_scope();
 
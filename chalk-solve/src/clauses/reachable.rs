@@ -0,0 +1,189 @@
+//! Computes which program clauses can be reached from a set of root goals,
+//! e.g. to report impls that are never used.
+//!
+//! Starting from the domain goals in the roots, we repeatedly collect the
+//! clauses that `program_clauses_for_goal` returns (which uses `could_match`
+//! to prune them), unify each with its goal and follow the domain goals in
+//! its conditions. Quantifiers are instantiated with inference variables, so
+//! `forall` is treated like `exists`; this can only make more clauses
+//! reachable. Goals found in conditions are followed in an empty environment.
+
+use super::builder::ClauseBuilder;
+use super::goal_walk::{is_too_large, push_domain_goals, GoalWalk};
+use super::program_clauses::ToProgramClauses;
+use super::{program_clauses_for_goal, push_default_associated_ty_value_clause};
+use crate::infer::InferenceTable;
+use crate::split::Split;
+use crate::RustIrDatabase;
+use chalk_ir::interner::Interner;
+use chalk_ir::*;
+use rustc_hash::FxHashSet;
+
+/// Returns the program clauses reachable from the goals in `roots`, in the
+/// order they were first reached.
+///
+/// If a goal flounders (e.g. `?T: Foo` for a non-enumerable trait `Foo`) or
+/// grows larger than `MAX_GOAL_SIZE`, the clauses of every impl of its trait
+/// that could match are considered reachable instead, and their conditions
+/// are not followed.
+pub fn reachable_clauses<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    roots: &[UCanonical<InEnvironment<Goal<I>>>],
+) -> Vec<ProgramClause<I>> {
+    let interner = db.interner();
+    let mut worklist = vec![];
+    for root in roots {
+        let (mut infer, _, InEnvironment { environment, goal }) =
+            InferenceTable::from_canonical(interner, root.universes, root.canonical.clone());
+        push_domain_goals(
+            interner,
+            &mut infer,
+            &environment,
+            &goal,
+            GoalWalk::OverApproximate,
+            &mut worklist,
+        );
+    }
+
+    let mut seen_goals = FxHashSet::default();
+    let mut seen_clauses = FxHashSet::default();
+    let mut clauses = vec![];
+    while let Some(goal) = worklist.pop() {
+        if !seen_goals.insert(goal.clone()) {
+            continue;
+        }
+
+        let (
+            mut infer,
+            _,
+            InEnvironment {
+                goal: domain_goal, ..
+            },
+        ) = InferenceTable::from_canonical(interner, goal.universes, goal.canonical.clone());
+        let goal_clauses = if is_too_large(interner, &mut infer, &domain_goal) {
+            None
+        } else {
+            program_clauses_for_goal(db, &goal).ok()
+        };
+        let goal_clauses = match goal_clauses {
+            Some(goal_clauses) => goal_clauses,
+            None => {
+                for clause in floundered_clauses(db, &goal) {
+                    if seen_clauses.insert(clause.clone()) {
+                        clauses.push(clause);
+                    }
+                }
+                continue;
+            }
+        };
+
+        let environment = Environment::new(interner);
+        for clause in goal_clauses {
+            let snapshot = infer.snapshot();
+            let implication =
+                infer.instantiate_binders_existentially(interner, clause.data(interner).0.clone());
+            let unified = infer.relate(
+                interner,
+                db.unification_database(),
+                &environment,
+                Variance::Invariant,
+                &implication.consequence,
+                &domain_goal,
+            );
+            if unified.is_ok() {
+                for condition in implication.conditions.iter(interner) {
+                    push_domain_goals(
+                        interner,
+                        &mut infer,
+                        &environment,
+                        condition,
+                        GoalWalk::OverApproximate,
+                        &mut worklist,
+                    );
+                }
+                if seen_clauses.insert(clause.clone()) {
+                    clauses.push(clause);
+                }
+            }
+            infer.rollback_to(snapshot);
+        }
+    }
+    clauses
+}
+
+/// Returns the local impls of `trait_id` none of whose clauses are reachable
+/// from the goals in `roots` (see `reachable_clauses`). Negative impls have
+/// no clauses and are never reported.
+pub fn unreachable_impls<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    roots: &[UCanonical<InEnvironment<Goal<I>>>],
+    trait_id: TraitId<I>,
+) -> Vec<ImplId<I>> {
+    let reachable: FxHashSet<_> = reachable_clauses(db, roots).into_iter().collect();
+    db.local_impls_to_coherence_check(trait_id)
+        .into_iter()
+        .filter(|&impl_id| db.impl_datum(impl_id).is_positive())
+        .filter(|&impl_id| {
+            !impl_clauses(db, impl_id)
+                .iter()
+                .any(|clause| reachable.contains(clause))
+        })
+        .collect()
+}
+
+/// The clauses of all impls that could match `goal`, a trait or projection
+/// goal, used when `goal` itself can't be followed.
+fn floundered_clauses<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    goal: &UCanonical<InEnvironment<DomainGoal<I>>>,
+) -> Vec<ProgramClause<I>> {
+    let interner = db.interner();
+    let (trait_id, trait_parameters) = match &goal.canonical.value.goal {
+        DomainGoal::Holds(WhereClause::Implemented(trait_ref)) => (
+            trait_ref.trait_id,
+            trait_ref.substitution.as_slice(interner),
+        ),
+        DomainGoal::Holds(WhereClause::AliasEq(AliasEq {
+            alias: AliasTy::Projection(projection),
+            ..
+        }))
+        | DomainGoal::Normalize(Normalize {
+            alias: AliasTy::Projection(projection),
+            ..
+        }) => (
+            db.associated_ty_data(projection.associated_ty_id).trait_id,
+            db.trait_parameters_from_projection(projection),
+        ),
+        _ => return vec![],
+    };
+    db.impls_for_trait(trait_id, trait_parameters, &goal.canonical.binders)
+        .into_iter()
+        .flat_map(|impl_id| impl_clauses(db, impl_id))
+        .collect()
+}
+
+/// The clauses generated for the impl `impl_id`: the rule for the trait
+/// itself and the rules for its associated type values, including those
+/// taken from defaults in the trait.
+fn impl_clauses<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    impl_id: ImplId<I>,
+) -> Vec<ProgramClause<I>> {
+    let interner = db.interner();
+    let impl_datum = db.impl_datum(impl_id);
+    let environment = Environment::new(interner);
+    let mut clauses = vec![];
+    let builder = &mut ClauseBuilder::new(db, &mut clauses);
+    impl_datum.to_program_clauses(builder, &environment);
+    for &atv_id in &impl_datum.associated_ty_value_ids {
+        db.associated_ty_value(atv_id)
+            .to_program_clauses(builder, &environment);
+    }
+    for &assoc_ty_id in &db.trait_datum(impl_datum.trait_id()).associated_ty_ids {
+        if !impl_datum.provides_associated_ty(db, assoc_ty_id) {
+            let associated_ty_datum = db.associated_ty_data(assoc_ty_id);
+            push_default_associated_ty_value_clause(builder, &impl_datum, &associated_ty_datum);
+        }
+    }
+    clauses
+}
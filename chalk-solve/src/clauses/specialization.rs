@@ -0,0 +1,82 @@
+use crate::infer::InferenceTable;
+use crate::RustIrDatabase;
+use chalk_ir::interner::Interner;
+use chalk_ir::*;
+
+/// Removes the impls in `impl_ids` that are overridden by a more specialized
+/// impl for the goal `trait_parameters`.
+///
+/// An impl `B` is overridden by an impl `A` if `A`'s header is strictly more
+/// specific than `B`'s (so coherence has established that `A` specializes
+/// `B`, see `more_specialized_than`) and `A` is known to apply to every
+/// instance of the goal. The latter is only decided syntactically: the goal
+/// has to be an instance of `A`'s header, and `A` must not have where
+/// clauses. Otherwise both impls are kept, and the solver may find the goal
+/// ambiguous.
+pub(super) fn remove_overridden_impls<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    impl_ids: Vec<ImplId<I>>,
+    trait_parameters: &[GenericArg<I>],
+    binders: &CanonicalVarKinds<I>,
+) -> Vec<ImplId<I>> {
+    let interner = db.interner();
+    let goal = Binders::new(
+        VariableKinds::from_iter(
+            interner,
+            binders.iter(interner).map(|kind| kind.kind.clone()),
+        ),
+        Substitution::from_iter(interner, trait_parameters),
+    );
+
+    let overriding: Vec<_> = impl_ids
+        .iter()
+        .copied()
+        .filter(|&impl_id| {
+            let impl_datum = db.impl_datum(impl_id);
+            impl_datum.is_positive()
+                && impl_datum.binders.skip_binders().where_clauses.is_empty()
+                && is_instance_of(db, goal.clone(), header(db, impl_id))
+        })
+        .collect();
+
+    impl_ids
+        .into_iter()
+        .filter(|&impl_id| {
+            !overriding.iter().any(|&specific| {
+                specific != impl_id
+                    && is_instance_of(db, header(db, specific), header(db, impl_id))
+                    && !is_instance_of(db, header(db, impl_id), header(db, specific))
+            })
+        })
+        .collect()
+}
+
+/// The trait parameters of the impl `impl_id`, e.g. `[T, u32]` for
+/// `impl<T> Foo<u32> for T`.
+fn header<I: Interner>(db: &dyn RustIrDatabase<I>, impl_id: ImplId<I>) -> Binders<Substitution<I>> {
+    db.impl_datum(impl_id)
+        .binders
+        .map_ref(|bound| bound.trait_ref.substitution.clone())
+}
+
+/// Whether every instance of `specific` is also an instance of `general`.
+fn is_instance_of<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    specific: Binders<Substitution<I>>,
+    general: Binders<Substitution<I>>,
+) -> bool {
+    let interner = db.interner();
+    let mut table = InferenceTable::new();
+    let specific = table.instantiate_binders_universally(interner, specific);
+    let general = table.instantiate_binders_existentially(interner, general);
+    table
+        .relate(
+            interner,
+            db.unification_database(),
+            &Environment::new(interner),
+            Variance::Invariant,
+            specific.as_slice(interner),
+            general.as_slice(interner),
+        )
+        .is_ok_and(|result| result.goals.is_empty())
+}
@@ -96,6 +96,7 @@ pub fn super_traits<I: Interner>(
                             WhereClause::AliasEq(_) => None,
                             WhereClause::LifetimeOutlives(..) => None,
                             WhereClause::TypeOutlives(..) => None,
+                            WhereClause::NotImplemented(_) => None,
                         })
                     })
                     .collect::<Vec<_>>()
@@ -0,0 +1,289 @@
+//! Sanity checks for constructed program clauses: the debruijn indices of
+//! their bound variables, and the kinds of the generic arguments passed to
+//! traits, ADTs and associated types. A malformed clause is not an error as
+//! far as the solver is concerned; it just silently means something else, so
+//! with the `validate-clauses` feature we check clauses as they are built.
+
+use crate::RustIrDatabase;
+use chalk_ir::interner::Interner;
+use chalk_ir::visit::{SuperVisit, Visit, Visitor};
+use chalk_ir::{
+    AdtId, AliasTy, AssocTypeId, BoundVar, DebruijnIndex, DomainGoal, FromEnv, GenericArgData,
+    ProgramClause, Substitution, TraitId, TraitRef, Ty, TyKind, VariableKind, VariableKinds,
+    WellFormed, WhereClause,
+};
+use std::fmt;
+use std::ops::ControlFlow;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BoundVarError {
+    /// The variable refers to a binder outside of the clause.
+    Escaping(BoundVar),
+    /// The variable refers to the clause's own binder, but that binder
+    /// has fewer variables than the index.
+    IndexOutOfRange { bound_var: BoundVar, len: usize },
+}
+
+impl fmt::Display for BoundVarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoundVarError::Escaping(bound_var) => {
+                write!(f, "bound variable `{:?}` escapes the clause", bound_var)
+            }
+            BoundVarError::IndexOutOfRange { bound_var, len } => write!(
+                f,
+                "bound variable `{:?}` is out of range for a binder with {} variables",
+                bound_var, len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BoundVarError {}
+
+/// Checks that every bound variable in `clause` refers to a binder within
+/// the clause, and that those referring to the clause's outermost
+/// `forall<..>` have an index within its variable kinds. Bound variables
+/// are reported relative to the clause's binder.
+pub fn validate_bound_vars<I: Interner>(
+    interner: I,
+    clause: &ProgramClause<I>,
+) -> Result<(), BoundVarError> {
+    let binders = &clause.data(interner).0;
+    let mut validator = BoundVarValidator {
+        interner,
+        binder_len: binders.len(interner),
+    };
+    match binders
+        .skip_binders()
+        .visit_with(&mut validator, DebruijnIndex::INNERMOST)
+    {
+        ControlFlow::Break(error) => Err(error),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+struct BoundVarValidator<I: Interner> {
+    interner: I,
+    binder_len: usize,
+}
+
+impl<I: Interner> Visitor<I> for BoundVarValidator<I> {
+    type BreakTy = BoundVarError;
+
+    fn as_dyn(&mut self) -> &mut dyn Visitor<I, BreakTy = Self::BreakTy> {
+        self
+    }
+
+    fn interner(&self) -> I {
+        self.interner
+    }
+
+    fn visit_free_var(
+        &mut self,
+        bound_var: BoundVar,
+        outer_binder: DebruijnIndex,
+    ) -> ControlFlow<BoundVarError> {
+        // `visit_free_var` is only called for variables that are free at
+        // `outer_binder`, so this always succeeds.
+        let bound_var = bound_var.shifted_out_to(outer_binder).unwrap();
+        match bound_var.index_if_innermost() {
+            Some(index) if index < self.binder_len => ControlFlow::Continue(()),
+            Some(_) => ControlFlow::Break(BoundVarError::IndexOutOfRange {
+                bound_var,
+                len: self.binder_len,
+            }),
+            None => ControlFlow::Break(BoundVarError::Escaping(bound_var)),
+        }
+    }
+}
+
+/// An item that was passed generic arguments whose kinds don't match its
+/// declared generics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KindMismatchItem<I: Interner> {
+    Trait(TraitId<I>),
+    Adt(AdtId<I>),
+    AssociatedTy(AssocTypeId<I>),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KindError<I: Interner> {
+    /// The argument at `index` is a type, lifetime or constant where the
+    /// item declares a different kind of parameter.
+    Mismatch {
+        item: KindMismatchItem<I>,
+        index: usize,
+    },
+    /// The item declares `expected` parameters but was given `actual`
+    /// arguments.
+    WrongArgCount {
+        item: KindMismatchItem<I>,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl<I: Interner> fmt::Display for KindError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KindError::Mismatch { item, index } => write!(
+                f,
+                "argument {} of `{:?}` does not match the kind of its parameter",
+                index, item
+            ),
+            KindError::WrongArgCount {
+                item,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "`{:?}` expects {} generic arguments but was given {}",
+                item, expected, actual
+            ),
+        }
+    }
+}
+
+impl<I: Interner> std::error::Error for KindError<I> {}
+
+/// Checks that the substitutions of the trait references, ADTs and
+/// associated types in `clause` have as many arguments as the item declares
+/// generic parameters, and that each argument has the kind of its parameter.
+pub fn validate_generic_arg_kinds<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    clause: &ProgramClause<I>,
+) -> Result<(), KindError<I>> {
+    let mut validator = KindValidator { db };
+    match clause.visit_with(&mut validator, DebruijnIndex::INNERMOST) {
+        ControlFlow::Break(error) => Err(error),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+struct KindValidator<'a, I: Interner> {
+    db: &'a dyn RustIrDatabase<I>,
+}
+
+impl<I: Interner> KindValidator<'_, I> {
+    fn check_args(
+        &self,
+        item: KindMismatchItem<I>,
+        kinds: &VariableKinds<I>,
+        substitution: &Substitution<I>,
+    ) -> ControlFlow<KindError<I>> {
+        let interner = self.db.interner();
+        let kinds = kinds.as_slice(interner);
+        let args = substitution.as_slice(interner);
+        if kinds.len() != args.len() {
+            return ControlFlow::Break(KindError::WrongArgCount {
+                item,
+                expected: kinds.len(),
+                actual: args.len(),
+            });
+        }
+        let mismatch = kinds.iter().zip(args).position(|(kind, arg)| {
+            !matches!(
+                (kind, arg.data(interner)),
+                (VariableKind::Ty(_), GenericArgData::Ty(_))
+                    | (VariableKind::Lifetime, GenericArgData::Lifetime(_))
+                    | (VariableKind::Const(_), GenericArgData::Const(_))
+            )
+        });
+        match mismatch {
+            Some(index) => ControlFlow::Break(KindError::Mismatch { item, index }),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    fn check_trait_ref(&self, trait_ref: &TraitRef<I>) -> ControlFlow<KindError<I>> {
+        let trait_datum = self.db.trait_datum(trait_ref.trait_id);
+        self.check_args(
+            KindMismatchItem::Trait(trait_ref.trait_id),
+            &trait_datum.binders.binders,
+            &trait_ref.substitution,
+        )
+    }
+
+    fn check_alias(&self, alias: &AliasTy<I>) -> ControlFlow<KindError<I>> {
+        match alias {
+            AliasTy::Projection(projection) => {
+                self.check_associated_ty(projection.associated_ty_id, &projection.substitution)
+            }
+            AliasTy::Opaque(_) => ControlFlow::Continue(()),
+        }
+    }
+
+    fn check_associated_ty(
+        &self,
+        id: AssocTypeId<I>,
+        substitution: &Substitution<I>,
+    ) -> ControlFlow<KindError<I>> {
+        let associated_ty_datum = self.db.associated_ty_data(id);
+        self.check_args(
+            KindMismatchItem::AssociatedTy(id),
+            &associated_ty_datum.binders.binders,
+            substitution,
+        )
+    }
+}
+
+impl<I: Interner> Visitor<I> for KindValidator<'_, I> {
+    type BreakTy = KindError<I>;
+
+    fn as_dyn(&mut self) -> &mut dyn Visitor<I, BreakTy = Self::BreakTy> {
+        self
+    }
+
+    fn interner(&self) -> I {
+        self.db.interner()
+    }
+
+    fn visit_ty(&mut self, ty: &Ty<I>, outer_binder: DebruijnIndex) -> ControlFlow<KindError<I>> {
+        match ty.kind(self.db.interner()) {
+            TyKind::Adt(id, substitution) => {
+                let adt_datum = self.db.adt_datum(*id);
+                self.check_args(
+                    KindMismatchItem::Adt(*id),
+                    &adt_datum.binders.binders,
+                    substitution,
+                )?;
+            }
+            TyKind::AssociatedType(id, substitution) => {
+                self.check_associated_ty(*id, substitution)?
+            }
+            TyKind::Alias(alias) => self.check_alias(alias)?,
+            _ => (),
+        }
+        ty.super_visit_with(self.as_dyn(), outer_binder)
+    }
+
+    fn visit_domain_goal(
+        &mut self,
+        domain_goal: &DomainGoal<I>,
+        outer_binder: DebruijnIndex,
+    ) -> ControlFlow<KindError<I>> {
+        match domain_goal {
+            DomainGoal::WellFormed(WellFormed::Trait(trait_ref))
+            | DomainGoal::FromEnv(FromEnv::Trait(trait_ref)) => self.check_trait_ref(trait_ref)?,
+            DomainGoal::Normalize(normalize) => self.check_alias(&normalize.alias)?,
+            _ => (),
+        }
+        domain_goal.super_visit_with(self.as_dyn(), outer_binder)
+    }
+
+    fn visit_where_clause(
+        &mut self,
+        where_clause: &WhereClause<I>,
+        outer_binder: DebruijnIndex,
+    ) -> ControlFlow<KindError<I>> {
+        match where_clause {
+            WhereClause::Implemented(trait_ref) | WhereClause::NotImplemented(trait_ref) => {
+                self.check_trait_ref(trait_ref)?
+            }
+            WhereClause::AliasEq(alias_eq) => self.check_alias(&alias_eq.alias)?,
+            WhereClause::LifetimeOutlives(_) | WhereClause::TypeOutlives(_) => (),
+        }
+        where_clause.super_visit_with(self.as_dyn(), outer_binder)
+    }
+}
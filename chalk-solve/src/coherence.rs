@@ -20,14 +20,15 @@ pub struct CoherenceSolver<'a, I: Interner> {
 
 #[derive(Debug)]
 pub enum CoherenceError<I: Interner> {
-    OverlappingImpls(TraitId<I>),
+    /// Two impls of the trait overlap and neither specializes the other.
+    OverlappingImpls(TraitId<I>, ImplId<I>, ImplId<I>),
     FailedOrphanCheck(TraitId<I>),
 }
 
 impl<I: Interner> fmt::Display for CoherenceError<I> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            CoherenceError::OverlappingImpls(id) => {
+            CoherenceError::OverlappingImpls(id, _, _) => {
                 write!(f, "overlapping impls of trait `{:?}`", id)
             }
             CoherenceError::FailedOrphanCheck(id) => {
@@ -66,6 +67,22 @@ impl<I: Interner> SpecializationPriorities<I> {
     }
 }
 
+/// Whether `impl_a` specializes `impl_b`, i.e. whether every type that
+/// `impl_a` applies to is also covered by `impl_b`. Both impls must be impls
+/// of the same trait. This uses a fresh solver from `solver_builder` to
+/// prove that `impl_a`'s where clauses imply `impl_b`'s.
+#[cfg(feature = "specialization")]
+pub fn more_specialized_than<I: Interner>(
+    impl_a: ImplId<I>,
+    impl_b: ImplId<I>,
+    db: &dyn RustIrDatabase<I>,
+    solver_builder: &dyn Fn() -> Box<dyn Solver<I>>,
+) -> bool {
+    let trait_id = db.impl_datum(impl_a).trait_id();
+    assert_eq!(trait_id, db.impl_datum(impl_b).trait_id());
+    CoherenceSolver::new(db, solver_builder, trait_id).specializes(impl_b, impl_a)
+}
+
 /// Impls with higher priority take precedence over impls with lower
 /// priority (if both apply to the same types). Impls with equal
 /// priority should never apply to the same set of input types.
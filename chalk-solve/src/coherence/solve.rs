@@ -40,7 +40,7 @@ impl<I: Interner> CoherenceSolver<'_, I> {
                     (true, false) => record_specialization(l_id, r_id),
                     (false, true) => record_specialization(r_id, l_id),
                     (_, _) => {
-                        return Err(CoherenceError::OverlappingImpls(self.trait_id));
+                        return Err(CoherenceError::OverlappingImpls(self.trait_id, l_id, r_id));
                     }
                 }
             }
@@ -136,8 +136,8 @@ impl<I: Interner> CoherenceSolver<'_, I> {
             // Goal was proven with a unique solution, so no impl was found that causes these two
             // to overlap
             Some(Solution::Unique(_)) => true,
-            // Goal was ambiguous, so there *may* be overlap
-            Some(Solution::Ambig(_)) |
+            // Goal was ambiguous, or we gave up, so there *may* be overlap
+            Some(Solution::Ambig(_)) | Some(Solution::Overflow) |
             // Goal cannot be proven, so there is some impl that causes overlap
             None => false,
         };
@@ -193,7 +193,11 @@ impl<I: Interner> CoherenceSolver<'_, I> {
     // }
     // ```
     #[instrument(level = "debug", skip(self))]
-    fn specializes(&self, less_special_id: ImplId<I>, more_special_id: ImplId<I>) -> bool {
+    pub(super) fn specializes(
+        &self,
+        less_special_id: ImplId<I>,
+        more_special_id: ImplId<I>,
+    ) -> bool {
         let more_special = &self.db.impl_datum(more_special_id);
         let less_special = &self.db.impl_datum(less_special_id);
         debug_span!("specializes", ?less_special, ?more_special);
@@ -26,6 +26,7 @@ impl<I: Interner> IsCoinductive<I> for Goal<I> {
                 WhereClause::AliasEq(..) => false,
                 WhereClause::LifetimeOutlives(..) => false,
                 WhereClause::TypeOutlives(..) => false,
+                WhereClause::NotImplemented(..) => false,
             },
             GoalData::DomainGoal(DomainGoal::WellFormed(WellFormed::Trait(..))) => true,
             GoalData::Quantified(QuantifierKind::ForAll, goal) => {
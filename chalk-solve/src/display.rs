@@ -14,6 +14,7 @@ use crate::{logging_db::RecordedItemId, split::Split, RustIrDatabase};
 mod utils;
 
 mod bounds;
+mod clauses;
 mod identifiers;
 mod items;
 mod render_trait;
@@ -102,6 +103,38 @@ where
     Ok(())
 }
 
+/// Writes a program clause in the `forall<..> { Consequence :- Conditions. }`
+/// notation used in the documentation. Bound variables are named after their
+/// binder position, so the output is deterministic.
+pub fn write_program_clause<F, I, DB, P>(
+    f: &mut F,
+    ws: &WriterState<I, DB, P>,
+    clause: &ProgramClause<I>,
+) -> Result
+where
+    F: std::fmt::Write + ?Sized,
+    I: Interner,
+    DB: RustIrDatabase<I>,
+    P: Borrow<DB>,
+{
+    write!(f, "{}", clause.display(&InternalWriterState::new(ws)))
+}
+
+/// Writes a domain goal in the same notation as [`write_program_clause`].
+pub fn write_domain_goal<F, I, DB, P>(
+    f: &mut F,
+    ws: &WriterState<I, DB, P>,
+    goal: &DomainGoal<I>,
+) -> Result
+where
+    F: std::fmt::Write + ?Sized,
+    I: Interner,
+    DB: RustIrDatabase<I>,
+    P: Borrow<DB>,
+{
+    write!(f, "{}", goal.display(&InternalWriterState::new(ws)))
+}
+
 /// Displays a set of bounds, all targeting `Self`, as just the trait names,
 /// separated by `+`.
 ///
@@ -167,6 +200,15 @@ fn display_self_where_clauses_as_bounds<'a, I: Interner>(
                             },
                             WhereClause::LifetimeOutlives(lifetime) => lifetime.display(s).fmt(f),
                             WhereClause::TypeOutlives(ty) => ty.display(s).fmt(f),
+                            WhereClause::NotImplemented(trait_ref) => write!(
+                                f,
+                                "!{}",
+                                display_type_with_generics(
+                                    s,
+                                    trait_ref.trait_id,
+                                    &trait_ref.substitution.as_slice(interner)[1..],
+                                )
+                            ),
                         }
                     })
                     .to_string()
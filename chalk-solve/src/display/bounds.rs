@@ -93,6 +93,19 @@ impl<I: Interner> RenderAsRust<I> for WhereClause<I> {
             WhereClause::AliasEq(alias_eq) => alias_eq.fmt(s, f),
             WhereClause::LifetimeOutlives(lifetime) => lifetime.display(s).fmt(f),
             WhereClause::TypeOutlives(ty) => ty.display(s).fmt(f),
+            WhereClause::NotImplemented(trait_ref) => {
+                let interner = s.db().interner();
+                write!(
+                    f,
+                    "{}: !{}",
+                    trait_ref.self_type_parameter(interner).display(s),
+                    display_type_with_generics(
+                        s,
+                        trait_ref.trait_id,
+                        &trait_ref.substitution.as_slice(interner)[1..]
+                    )
+                )
+            }
         }
     }
 }
@@ -0,0 +1,216 @@
+//! Writer logic for program clauses and goals.
+//!
+//! Renders clauses in the notation used throughout the documentation:
+//!
+//! ```notrust
+//! forall<_1_0> {
+//!   Implemented(Foo<_1_0>: Send) :-
+//!     Implemented(_1_0: Send).
+//! }
+//! ```
+use std::fmt::{Formatter, Result};
+
+use chalk_ir::{interner::Interner, *};
+use itertools::Itertools;
+
+use super::{render_trait::RenderAsRust, state::InternalWriterState};
+
+impl<I: Interner> RenderAsRust<I> for ProgramClause<I> {
+    fn fmt(&self, s: &InternalWriterState<'_, I>, f: &'_ mut Formatter<'_>) -> Result {
+        let interner = s.db().interner();
+        let implication = &self.data(interner).0;
+        if implication.binders.is_empty(interner) {
+            return implication.skip_binders().fmt(s, f);
+        }
+        let s = &s.add_debrujin_index(None);
+        writeln!(
+            f,
+            "forall<{}> {{",
+            s.binder_var_display(&implication.binders).format(", ")
+        )?;
+        let inner = &s.add_indent();
+        writeln!(
+            f,
+            "{}{}",
+            inner.indent(),
+            implication.skip_binders().display(inner)
+        )?;
+        write!(f, "{}}}", s.indent())
+    }
+}
+
+impl<I: Interner> RenderAsRust<I> for ProgramClauseImplication<I> {
+    fn fmt(&self, s: &InternalWriterState<'_, I>, f: &'_ mut Formatter<'_>) -> Result {
+        let interner = s.db().interner();
+        let conditions = self
+            .conditions
+            .iter(interner)
+            .map(|goal| goal.display(s).to_string())
+            .chain(
+                self.constraints
+                    .iter(interner)
+                    .map(|constraint| constraint.goal.display(s).to_string()),
+            )
+            .collect::<Vec<_>>();
+        write!(f, "{}", self.consequence.display(s))?;
+        if !conditions.is_empty() {
+            let inner = s.add_indent();
+            write!(
+                f,
+                " :-\n{}",
+                conditions
+                    .iter()
+                    .map(|condition| format!("{}{}", inner.indent(), condition))
+                    .format(",\n")
+            )?;
+        }
+        write!(f, ".")
+    }
+}
+
+/// Renders a hypothesis of an `if (...)` goal on a single line, without the
+/// trailing period.
+fn display_hypothesis<I: Interner>(
+    s: &InternalWriterState<'_, I>,
+    clause: &ProgramClause<I>,
+) -> String {
+    let interner = s.db().interner();
+    let implication = &clause.data(interner).0;
+    let s = &s.add_debrujin_index(None);
+    let ProgramClauseImplication {
+        consequence,
+        conditions,
+        ..
+    } = implication.skip_binders();
+    let mut out = consequence.display(s).to_string();
+    if !conditions.is_empty(interner) {
+        out = format!(
+            "{} :- {}",
+            out,
+            conditions
+                .iter(interner)
+                .map(|goal| goal.display(s))
+                .format(", ")
+        );
+    }
+    if implication.binders.is_empty(interner) {
+        out
+    } else {
+        format!(
+            "forall<{}> {{ {} }}",
+            s.binder_var_display(&implication.binders).format(", "),
+            out
+        )
+    }
+}
+
+impl<I: Interner> RenderAsRust<I> for Goal<I> {
+    fn fmt(&self, s: &InternalWriterState<'_, I>, f: &'_ mut Formatter<'_>) -> Result {
+        let interner = s.db().interner();
+        match self.data(interner) {
+            GoalData::Quantified(kind, subgoal) => {
+                let s = &s.add_debrujin_index(None);
+                if subgoal.binders.is_empty(interner) {
+                    return subgoal.skip_binders().fmt(s, f);
+                }
+                let kind = match kind {
+                    QuantifierKind::ForAll => "forall",
+                    QuantifierKind::Exists => "exists",
+                };
+                write!(
+                    f,
+                    "{}<{}> {{ {} }}",
+                    kind,
+                    s.binder_var_display(&subgoal.binders).format(", "),
+                    subgoal.skip_binders().display(s)
+                )?;
+                Ok(())
+            }
+            GoalData::Implies(clauses, subgoal) => write!(
+                f,
+                "if ({}) {{ {} }}",
+                clauses
+                    .iter(interner)
+                    .map(|clause| display_hypothesis(s, clause))
+                    .format("; "),
+                subgoal.display(s)
+            ),
+            GoalData::All(goals) => write!(
+                f,
+                "{}",
+                goals
+                    .iter(interner)
+                    .map(|goal| goal.display(s))
+                    .format(", ")
+            ),
+            GoalData::Not(subgoal) => write!(f, "not {{ {} }}", subgoal.display(s)),
+            GoalData::EqGoal(EqGoal { a, b }) => write!(f, "{} = {}", a.display(s), b.display(s)),
+            GoalData::SubtypeGoal(SubtypeGoal { a, b }) => {
+                write!(f, "Subtype({}, {})", a.display(s), b.display(s))
+            }
+            GoalData::DomainGoal(domain_goal) => domain_goal.fmt(s, f),
+            GoalData::CannotProve => write!(f, "CannotProve"),
+        }
+    }
+}
+
+impl<I: Interner> RenderAsRust<I> for DomainGoal<I> {
+    fn fmt(&self, s: &InternalWriterState<'_, I>, f: &'_ mut Formatter<'_>) -> Result {
+        match self {
+            DomainGoal::Holds(WhereClause::Implemented(trait_ref)) => {
+                write!(f, "Implemented({})", trait_ref.display(s))
+            }
+            DomainGoal::Holds(WhereClause::AliasEq(AliasEq { alias, ty })) => {
+                write!(f, "AliasEq({} = {})", alias.display(s), ty.display(s))
+            }
+            DomainGoal::Holds(WhereClause::LifetimeOutlives(outlives)) => {
+                write!(f, "Outlives({})", outlives.display(s))
+            }
+            DomainGoal::Holds(WhereClause::TypeOutlives(outlives)) => {
+                write!(f, "Outlives({})", outlives.display(s))
+            }
+            DomainGoal::Holds(WhereClause::NotImplemented(trait_ref)) => {
+                write!(f, "NotImplemented({})", trait_ref.display(s))
+            }
+            DomainGoal::WellFormed(WellFormed::Trait(trait_ref)) => {
+                write!(f, "WellFormed({})", trait_ref.display(s))
+            }
+            DomainGoal::WellFormed(WellFormed::Ty(ty)) => {
+                write!(f, "WellFormed({})", ty.display(s))
+            }
+            DomainGoal::FromEnv(FromEnv::Trait(trait_ref)) => {
+                write!(f, "FromEnv({})", trait_ref.display(s))
+            }
+            DomainGoal::FromEnv(FromEnv::Ty(ty)) => write!(f, "FromEnv({})", ty.display(s)),
+            DomainGoal::Normalize(Normalize { alias, ty }) => {
+                write!(f, "Normalize({} -> {})", alias.display(s), ty.display(s))
+            }
+            // Associated consts have no names in the display module yet, so
+            // the projection falls back to its `Debug` output.
+            DomainGoal::ConstEq(ConstEq { projection, value }) => {
+                write!(f, "ConstEq({:?} = {})", projection, value.display(s))
+            }
+            DomainGoal::IsLocal(ty) => write!(f, "IsLocal({})", ty.display(s)),
+            DomainGoal::IsUpstream(ty) => write!(f, "IsUpstream({})", ty.display(s)),
+            DomainGoal::IsFullyVisible(ty) => write!(f, "IsFullyVisible({})", ty.display(s)),
+            DomainGoal::LocalImplAllowed(trait_ref) => {
+                write!(f, "LocalImplAllowed({})", trait_ref.display(s))
+            }
+            DomainGoal::Compatible => write!(f, "Compatible"),
+            DomainGoal::DownstreamType(ty) => write!(f, "DownstreamType({})", ty.display(s)),
+            DomainGoal::Reveal => write!(f, "Reveal"),
+            DomainGoal::ObjectSafe(trait_id) => write!(f, "ObjectSafe({})", trait_id.display(s)),
+        }
+    }
+}
+
+impl<I: Interner> RenderAsRust<I> for Constraint<I> {
+    fn fmt(&self, s: &InternalWriterState<'_, I>, f: &'_ mut Formatter<'_>) -> Result {
+        match self {
+            Constraint::LifetimeOutlives(a, b) => write!(f, "{}: {}", a.display(s), b.display(s)),
+            Constraint::TypeOutlives(ty, lifetime) => {
+                write!(f, "{}: {}", ty.display(s), lifetime.display(s))
+            }
+        }
+    }
+}
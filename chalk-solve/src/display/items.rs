@@ -8,6 +8,7 @@ use std::fmt::{Formatter, Result};
 use crate::rust_ir::*;
 use crate::split::Split;
 use chalk_ir::interner::Interner;
+use chalk_ir::{AliasEq, AliasTy, TraitRef, VariableKinds, WhereClause};
 use itertools::Itertools;
 
 use super::{
@@ -170,7 +171,45 @@ impl<I: Interner> RenderAsRust<I> for TraitDatum<I> {
         let s = &s.add_debrujin_index(Some(0));
         let value = self.binders.skip_binders();
 
-        // flags
+        // trait alias, with its where clauses as the bounds
+        if self.flags.alias {
+            // A bound like `Iterator<Item = T>` lowers to both an `AliasEq`
+            // and an `Implemented` where clause; only the former is written.
+            let implied_by_alias_eq = |trait_ref: &TraitRef<I>, binders: &VariableKinds<I>| {
+                value.where_clauses.iter().any(|other| {
+                    other.binders == *binders
+                        && matches!(
+                            other.skip_binders(),
+                            WhereClause::AliasEq(AliasEq {
+                                alias: AliasTy::Projection(projection),
+                                ..
+                            }) if s.db().trait_ref_from_projection(projection) == *trait_ref
+                        )
+                })
+            };
+            let bounds: Vec<_> = value
+                .where_clauses
+                .iter()
+                .filter(|qwc| match qwc.skip_binders() {
+                    WhereClause::Implemented(trait_ref) => {
+                        !implied_by_alias_eq(trait_ref, &qwc.binders)
+                    }
+                    _ => true,
+                })
+                .cloned()
+                .collect();
+
+            let binders = s.binder_var_display(&self.binders.binders).skip(1);
+            write!(f, "trait {}", self.id.display(s))?;
+            write_joined_non_empty_list!(f, "<{}>", binders, ", ")?;
+            return write!(
+                f,
+                " = {};",
+                display_self_where_clauses_as_bounds(s, &bounds)
+            );
+        }
+
+        // flags (`alias` is always false here)
         write_flags!(
             f,
             self.flags,
@@ -180,7 +219,8 @@ impl<I: Interner> RenderAsRust<I> for TraitDatum<I> {
                 upstream,
                 fundamental,
                 non_enumerable,
-                coinductive
+                coinductive,
+                alias
             }
         );
 
@@ -204,6 +244,7 @@ impl<I: Interner> RenderAsRust<I> for TraitDatum<I> {
                 WellKnownTrait::CoerceUnsized => "coerce_unsized",
                 WellKnownTrait::DiscriminantKind => "discriminant_kind",
                 WellKnownTrait::Generator => "generator",
+                WellKnownTrait::Tuple => "tuple_trait",
             };
             writeln!(f, "#[lang({})]", name)?;
         }
@@ -224,15 +265,15 @@ impl<I: Interner> RenderAsRust<I> for TraitDatum<I> {
         // body
         write!(f, "{{")?;
         let s = &s.add_indent();
-        write_joined_non_empty_list!(
-            f,
-            "\n{}\n",
-            self.associated_ty_ids.iter().map(|assoc_ty_id| {
-                let assoc_ty_data = s.db().associated_ty_data(*assoc_ty_id);
-                format!("{}{}", s.indent(), (*assoc_ty_data).display(s))
-            }),
-            "\n"
-        )?;
+        let assoc_tys = self.associated_ty_ids.iter().map(|assoc_ty_id| {
+            let assoc_ty_data = s.db().associated_ty_data(*assoc_ty_id);
+            format!("{}{}", s.indent(), (*assoc_ty_data).display(s))
+        });
+        let assoc_consts = self.associated_const_ids.iter().map(|assoc_const_id| {
+            let assoc_const_data = s.db().associated_const_data(*assoc_const_id);
+            format!("{}{}", s.indent(), (*assoc_const_data).display(s))
+        });
+        write_joined_non_empty_list!(f, "\n{}\n", assoc_tys.chain(assoc_consts), "\n")?;
         write!(f, "}}")?;
         Ok(())
     }
@@ -304,7 +345,16 @@ impl<I: Interner> RenderAsRust<I> for ImplDatum<I> {
                     .display(s)
                     .to_string()
             });
-            write_joined_non_empty_list!(f, "\n{}\n", assoc_ty_values, "\n")?;
+            let assoc_const_values = self
+                .associated_const_values
+                .iter()
+                .map(|assoc_const_value| assoc_const_value.display(s).to_string());
+            write_joined_non_empty_list!(
+                f,
+                "\n{}\n",
+                assoc_ty_values.chain(assoc_const_values),
+                "\n"
+            )?;
         }
         write!(f, "}}")?;
         Ok(())
@@ -403,11 +453,40 @@ impl<I: Interner> RenderAsRust<I> for AssociatedTyDatum<I> {
             let where_clauses = datum_bounds.where_clauses.display(where_s);
             write!(f, "\n{}where\n{}", s.indent(), where_clauses)?;
         }
+        if let Some(default) = &datum_bounds.default {
+            write!(f, " = {}", default.display(s))?;
+        }
         write!(f, ";")?;
         Ok(())
     }
 }
 
+impl<I: Interner> RenderAsRust<I> for AssociatedConstDatum<I> {
+    fn fmt(&self, s: &InternalWriterState<'_, I>, f: &'_ mut Formatter<'_>) -> Result {
+        // The const's binders are exactly those of its trait, so it can be
+        // rendered directly in the trait's environment.
+        write!(
+            f,
+            "const {}: {};",
+            self.id.display(s),
+            self.ty.skip_binders().display(s)
+        )
+    }
+}
+
+impl<I: Interner> RenderAsRust<I> for AssociatedConstValue<I> {
+    fn fmt(&self, s: &InternalWriterState<'_, I>, f: &'_ mut Formatter<'_>) -> Result {
+        // As for the datum, the value's binders are exactly those of its impl.
+        write!(
+            f,
+            "{}const {} = {};",
+            s.indent(),
+            self.associated_const_id.display(s),
+            self.value.skip_binders().display(s)
+        )
+    }
+}
+
 impl<I: Interner> RenderAsRust<I> for AssociatedTyValue<I> {
     fn fmt(&self, s: &InternalWriterState<'_, I>, f: &'_ mut Formatter<'_>) -> Result {
         // see comments for a similar empty env operation in AssociatedTyDatum's
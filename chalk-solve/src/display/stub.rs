@@ -51,11 +51,26 @@ impl<I: Interner, DB: RustIrDatabase<I>> RustIrDatabase<I> for StubWrapper<'_, D
             AssociatedTyDatumBound {
                 where_clauses: Vec::new(),
                 bounds: Vec::new(),
+                default: None,
             },
         );
         Arc::new(v)
     }
 
+    fn associated_const_data(
+        &self,
+        id: chalk_ir::AssocConstId<I>,
+    ) -> std::sync::Arc<crate::rust_ir::AssociatedConstDatum<I>> {
+        self.db.associated_const_data(id)
+    }
+
+    fn default_methods(
+        &self,
+        trait_id: chalk_ir::TraitId<I>,
+    ) -> Vec<std::sync::Arc<crate::rust_ir::DefaultMethodDatum<I>>> {
+        self.db.default_methods(trait_id)
+    }
+
     fn trait_datum(
         &self,
         trait_id: chalk_ir::TraitId<I>,
@@ -86,6 +101,10 @@ impl<I: Interner, DB: RustIrDatabase<I>> RustIrDatabase<I> for StubWrapper<'_, D
         Arc::new(v)
     }
 
+    fn adt_ids(&self) -> Vec<chalk_ir::AdtId<I>> {
+        self.db.adt_ids()
+    }
+
     fn adt_repr(&self, id: chalk_ir::AdtId<I>) -> std::sync::Arc<crate::rust_ir::AdtRepr<I>> {
         self.db.adt_repr(id)
     }
@@ -169,6 +188,16 @@ impl<I: Interner, DB: RustIrDatabase<I>> RustIrDatabase<I> for StubWrapper<'_, D
         unimplemented!("stub display code should call this")
     }
 
+    fn impls_provided_for(
+        &self,
+        _auto_trait_id: chalk_ir::TraitId<I>,
+        _ty: &chalk_ir::TyKind<I>,
+    ) -> Vec<chalk_ir::ImplId<I>> {
+        // We panic here because the returned ids may not be collected,
+        // resulting in unresolvable names.
+        unimplemented!("stub display code should call this")
+    }
+
     fn well_known_trait_id(
         &self,
         well_known_trait: crate::rust_ir::WellKnownTrait,
@@ -250,6 +279,10 @@ impl<I: Interner, DB: RustIrDatabase<I>> RustIrDatabase<I> for StubWrapper<'_, D
         self.db.assoc_type_name(assoc_ty_id)
     }
 
+    fn assoc_const_name(&self, assoc_const_id: chalk_ir::AssocConstId<I>) -> String {
+        self.db.assoc_const_name(assoc_const_id)
+    }
+
     fn opaque_type_name(&self, opaque_ty_id: chalk_ir::OpaqueTyId<I>) -> String {
         self.db.opaque_type_name(opaque_ty_id)
     }
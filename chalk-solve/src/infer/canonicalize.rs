@@ -6,7 +6,7 @@ use chalk_ir::*;
 use std::cmp::max;
 use tracing::{debug, instrument};
 
-use super::{InferenceTable, ParameterEnaVariable};
+use super::{InferenceTable, ParameterEnaVariable, ParameterEnaVariableExt};
 
 impl<I: Interner> InferenceTable<I> {
     /// Given a value `value` with variables in it, replaces those variables
@@ -50,6 +50,32 @@ impl<I: Interner> InferenceTable<I> {
             free_vars,
         }
     }
+
+    /// Checks that canonicalizing `value` is undone by instantiating the
+    /// result, up to a renaming of the inference variables. This is what lets
+    /// caches key on canonical goals, and is meant to be used in
+    /// `debug_assert!`s.
+    ///
+    /// Both the original free variables (see `Canonicalized::instantiate`)
+    /// and fresh variables from `instantiate_canonical` must canonicalize
+    /// back to the same canonical value. The fresh variables are rolled back
+    /// afterwards, so the table is left unchanged.
+    pub fn canonical_round_trips<T>(&mut self, interner: I, value: &T) -> bool
+    where
+        T: Fold<I, Result = T> + HasInterner<Interner = I> + Clone + Eq + std::fmt::Debug,
+    {
+        let canonicalized = self.canonicalize(interner, value.clone());
+        let restored = canonicalized.instantiate(interner);
+        if self.canonicalize(interner, restored).quantified != canonicalized.quantified {
+            return false;
+        }
+
+        let snapshot = self.snapshot();
+        let fresh = self.instantiate_canonical(interner, canonicalized.quantified.clone());
+        let round_trips = self.canonicalize(interner, fresh).quantified == canonicalized.quantified;
+        self.rollback_to(snapshot);
+        round_trips
+    }
 }
 
 #[derive(Debug)]
@@ -61,6 +87,22 @@ pub struct Canonicalized<T: HasInterner> {
     pub free_vars: Vec<ParameterEnaVariable<T::Interner>>,
 }
 
+impl<T: HasInterner> Canonicalized<T> {
+    /// Substitutes the free variables back into the canonicalized value,
+    /// the inverse of `InferenceTable::canonicalize`. Variables that were
+    /// unified with each other come back as their common root.
+    pub fn instantiate(&self, interner: T::Interner) -> T
+    where
+        T: Fold<T::Interner, Result = T> + Clone,
+    {
+        let subst = Substitution::from_iter(
+            interner,
+            self.free_vars.iter().map(|v| v.to_generic_arg(interner)),
+        );
+        subst.apply(self.quantified.value.clone(), interner)
+    }
+}
+
 struct Canonicalizer<'q, I: Interner> {
     table: &'q mut InferenceTable<I>,
     free_vars: Vec<ParameterEnaVariable<I>>,
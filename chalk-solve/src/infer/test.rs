@@ -420,3 +420,113 @@ fn lifetime_constraint_indirect() {
         "InEnvironment { environment: Env([]), goal: \'!1_0: \'?2 }",
     );
 }
+
+fn implemented_goal(args: Vec<GenericArg<ChalkIr>>) -> Goal<ChalkIr> {
+    let interner = ChalkIr;
+    let trait_ref = TraitRef {
+        trait_id: TraitId(chalk_integration::interner::RawId { index: 0 }),
+        substitution: Substitution::from_iter(interner, args),
+    };
+    GoalData::DomainGoal(DomainGoal::Holds(WhereClause::Implemented(trait_ref))).intern(interner)
+}
+
+#[test]
+fn canonical_round_trip_goals() {
+    let interner = ChalkIr;
+    let mut table = make_table();
+    let environment0 = Environment::new(interner);
+
+    let v0 = table.new_variable(U0).to_ty(interner);
+    let v1 = table.new_variable(U1).to_ty(interner);
+    let v2a = table.new_variable(U2).to_ty(interner);
+    let v2b = table.new_variable(U2).to_ty(interner);
+    let l0 = table.new_variable(U0).to_lifetime(interner);
+    let v2c = table.new_variable(U2).to_ty(interner);
+
+    // Bind `?2b` to a type mentioning other variables and unify `?2c`
+    // with `?1`, so some of the variables below are not roots.
+    table
+        .relate(
+            interner,
+            &TestDatabase,
+            &environment0,
+            Variance::Invariant,
+            &v2b,
+            &ty!(apply (item 1) (expr v1) (lifetime (expr l0))),
+        )
+        .unwrap();
+    table
+        .relate(
+            interner,
+            &TestDatabase,
+            &environment0,
+            Variance::Invariant,
+            &v2c,
+            &v1,
+        )
+        .unwrap();
+
+    let args: Vec<GenericArg<ChalkIr>> = vec![
+        arg!((expr v0)),
+        arg!((expr v1)),
+        arg!((expr v2a)),
+        arg!((expr v2b)),
+        arg!((expr v2c)),
+        arg!((lifetime (expr l0))),
+        arg!((apply (item 2) (expr v2a) (expr v0))),
+        arg!((function 1 (apply (item 0) (bound 0) (expr v2c)))),
+    ];
+
+    // Check every ordered pair and triple of the arguments above, so
+    // variables show up in every order and repeated.
+    for a in &args {
+        for b in &args {
+            let goal = implemented_goal(vec![a.clone(), b.clone()]);
+            assert!(table.canonical_round_trips(interner, &goal), "{:?}", goal);
+            for c in &args {
+                let goal = implemented_goal(vec![a.clone(), b.clone(), c.clone()]);
+                assert!(table.canonical_round_trips(interner, &goal), "{:?}", goal);
+            }
+        }
+    }
+
+    // The fresh variables created by the check are rolled back.
+    assert_eq!(table.new_variable(U0).to_ty(interner), ty!(infer 6),);
+}
+
+#[test]
+fn canonicalized_instantiate_restores_roots() {
+    let interner = ChalkIr;
+    let mut table = make_table();
+    let environment0 = Environment::new(interner);
+
+    let v0 = table.new_variable(U0).to_ty(interner);
+    let v1 = table.new_variable(U1).to_ty(interner);
+    let v2 = table.new_variable(U0).to_ty(interner);
+
+    let goal = implemented_goal(vec![arg!((expr v1)), arg!((expr v0)), arg!((expr v1))]);
+    let canonicalized = table.canonicalize(interner, goal.clone());
+    assert_eq!(canonicalized.instantiate(interner), goal);
+
+    // Once `?2` is unified with `?0`, both come back as whichever of them
+    // is the root.
+    table
+        .relate(
+            interner,
+            &TestDatabase,
+            &environment0,
+            Variance::Invariant,
+            &v2,
+            &v0,
+        )
+        .unwrap();
+    let root = table.inference_var_root(v0.inference_var(interner).unwrap());
+    let root = root.to_ty(interner, TyVariableKind::General);
+    let goal = implemented_goal(vec![arg!((expr v0)), arg!((expr v2))]);
+    let canonicalized = table.canonicalize(interner, goal);
+    assert_eq!(canonicalized.quantified.binders.len(interner), 1);
+    assert_eq!(
+        canonicalized.instantiate(interner),
+        implemented_goal(vec![arg!((expr root)), arg!((expr root))]),
+    );
+}
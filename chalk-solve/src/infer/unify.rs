@@ -628,6 +628,21 @@ impl<'t, I: Interner> Unifier<'t, I> {
                                         trait_id,
                                     })
                                 }
+                                WhereClause::NotImplemented(trait_ref) => {
+                                    let TraitRef {
+                                        ref substitution,
+                                        trait_id,
+                                    } = *trait_ref;
+                                    let substitution = self.generalize_substitution_skip_self(
+                                        substitution,
+                                        universe_index,
+                                        |_| Some(variance),
+                                    );
+                                    WhereClause::NotImplemented(TraitRef {
+                                        substitution,
+                                        trait_id,
+                                    })
+                                }
                                 WhereClause::AliasEq(alias_eq) => {
                                     let AliasEq { alias, ty: _ } = alias_eq;
                                     let alias = match alias {
@@ -49,12 +49,36 @@ pub trait RustIrDatabase<I: Interner>: Debug {
     /// Returns the datum for the associated type with the given id.
     fn associated_ty_data(&self, ty: AssocTypeId<I>) -> Arc<AssociatedTyDatum<I>>;
 
+    /// Returns the datum for the associated const with the given id.
+    ///
+    /// This is only asked for the ids listed in the `associated_const_ids`
+    /// of some `TraitDatum`, so databases without associated consts can
+    /// rely on the default, which panics.
+    fn associated_const_data(&self, id: AssocConstId<I>) -> Arc<AssociatedConstDatum<I>> {
+        panic!("no datum for associated const {:?}", id)
+    }
+
+    /// Returns the methods with a default body defined in the given trait.
+    /// The default returns none.
+    fn default_methods(&self, _trait_id: TraitId<I>) -> Vec<Arc<DefaultMethodDatum<I>>> {
+        Vec::new()
+    }
+
     /// Returns the datum for the definition with the given id.
     fn trait_datum(&self, trait_id: TraitId<I>) -> Arc<TraitDatum<I>>;
 
     /// Returns the datum for the ADT with the given id.
     fn adt_datum(&self, adt_id: AdtId<I>) -> Arc<AdtDatum<I>>;
 
+    /// Returns the ids of all ADTs in the program. These are used to
+    /// enumerate the automatic impls of auto traits for goals like `?T: Send`,
+    /// which costs time proportional to the number of ADTs every time such a
+    /// goal is solved. The default returns no ids, in which case those goals
+    /// flounder instead.
+    fn adt_ids(&self) -> Vec<AdtId<I>> {
+        Vec::new()
+    }
+
     /// Returns the generator datum for the generator with the given id.
     fn generator_datum(&self, generator_id: GeneratorId<I>) -> Arc<GeneratorDatum<I>>;
 
@@ -116,6 +140,17 @@ pub trait RustIrDatabase<I: Interner>: Debug {
     /// (otherwise, we rely on the impls the user gave).
     fn impl_provided_for(&self, auto_trait_id: TraitId<I>, ty: &TyKind<I>) -> bool;
 
+    /// Returns the explicit impls of the auto trait `auto_trait_id` for
+    /// types with the same head as `ty` (the same ADT, the same scalar, a
+    /// tuple of the same arity, ...), whatever their parameters. Knowing
+    /// the impls lets us keep the default impls for the instances of `ty`
+    /// that a specific impl like `impl Send for MyList<Foo>` doesn't cover
+    /// (see `auto_trait_impl_suppressed`). The default returns no impls, in
+    /// which case we fall back to `impl_provided_for`.
+    fn impls_provided_for(&self, _auto_trait_id: TraitId<I>, _ty: &TyKind<I>) -> Vec<ImplId<I>> {
+        Vec::new()
+    }
+
     /// Returns id of a trait lang item, if found
     fn well_known_trait_id(&self, well_known_trait: WellKnownTrait) -> Option<TraitId<I>>;
 
@@ -125,7 +160,9 @@ pub trait RustIrDatabase<I: Interner>: Debug {
 
     fn interner(&self) -> I;
 
-    /// Check if a trait is object safe
+    /// Check if a trait is object safe. Chalk does not model trait methods,
+    /// so this is an oracle: the caller is responsible for the method-level
+    /// rules, such as ignoring methods bounded by `where Self: Sized`.
     fn is_object_safe(&self, trait_id: TraitId<I>) -> bool;
 
     /// Gets the `ClosureKind` for a given closure and substitution.
@@ -181,6 +218,12 @@ pub trait RustIrDatabase<I: Interner>: Debug {
         sanitize_debug_name(|f| I::debug_assoc_type_id(assoc_ty_id, f))
     }
 
+    /// Retrieves the name of an associated const. No uniqueness guarantees, but must
+    /// a valid Rust identifier.
+    fn assoc_const_name(&self, assoc_const_id: AssocConstId<I>) -> String {
+        sanitize_debug_name(|f| I::debug_assoc_const_id(assoc_const_id, f))
+    }
+
     /// Retrieves the name of an opaque type. No uniqueness guarantees, but must
     /// a valid Rust identifier.
     fn opaque_type_name(&self, opaque_ty_id: OpaqueTyId<I>) -> String {
@@ -197,6 +240,7 @@ pub trait RustIrDatabase<I: Interner>: Debug {
     fn discriminant_type(&self, ty: Ty<I>) -> Ty<I>;
 }
 
+pub use clauses::any_impl_could_match;
 pub use clauses::program_clauses_for_env;
 
 pub use solve::Guidance;
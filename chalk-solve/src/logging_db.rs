@@ -128,6 +128,23 @@ where
         ty_datum
     }
 
+    fn associated_const_data(
+        &self,
+        id: chalk_ir::AssocConstId<I>,
+    ) -> Arc<crate::rust_ir::AssociatedConstDatum<I>> {
+        let const_datum = self.ws.db().associated_const_data(id);
+        self.record(const_datum.trait_id);
+        const_datum
+    }
+
+    fn default_methods(
+        &self,
+        trait_id: TraitId<I>,
+    ) -> Vec<Arc<crate::rust_ir::DefaultMethodDatum<I>>> {
+        self.record(trait_id);
+        self.ws.db().default_methods(trait_id)
+    }
+
     fn trait_datum(&self, trait_id: TraitId<I>) -> Arc<TraitDatum<I>> {
         self.record(trait_id);
         self.ws.db().trait_datum(trait_id)
@@ -138,6 +155,12 @@ where
         self.ws.db().adt_datum(adt_id)
     }
 
+    fn adt_ids(&self) -> Vec<AdtId<I>> {
+        let adt_ids = self.ws.db().adt_ids();
+        self.record_all(adt_ids.iter().copied());
+        adt_ids
+    }
+
     fn generator_datum(&self, generator_id: GeneratorId<I>) -> Arc<GeneratorDatum<I>> {
         self.record(generator_id);
         self.ws.db().borrow().generator_datum(generator_id)
@@ -205,6 +228,16 @@ where
         self.ws.db().impl_provided_for(auto_trait_id, ty)
     }
 
+    fn impls_provided_for(&self, auto_trait_id: TraitId<I>, ty: &TyKind<I>) -> Vec<ImplId<I>> {
+        self.record(auto_trait_id);
+        if let TyKind::Adt(adt_id, _) = ty {
+            self.record(*adt_id);
+        }
+        let impl_ids = self.ws.db().impls_provided_for(auto_trait_id, ty);
+        self.record_all(impl_ids.iter().copied());
+        impl_ids
+    }
+
     fn well_known_trait_id(
         &self,
         well_known_trait: crate::rust_ir::WellKnownTrait,
@@ -237,6 +270,10 @@ where
         self.ws.db().assoc_type_name(assoc_ty_id)
     }
 
+    fn assoc_const_name(&self, assoc_const_id: AssocConstId<I>) -> String {
+        self.ws.db().assoc_const_name(assoc_const_id)
+    }
+
     fn opaque_type_name(&self, opaque_ty_id: OpaqueTyId<I>) -> String {
         self.ws.db().opaque_type_name(opaque_ty_id)
     }
@@ -395,6 +432,20 @@ where
         self.db.associated_ty_data(ty)
     }
 
+    fn associated_const_data(
+        &self,
+        id: chalk_ir::AssocConstId<I>,
+    ) -> Arc<crate::rust_ir::AssociatedConstDatum<I>> {
+        self.db.associated_const_data(id)
+    }
+
+    fn default_methods(
+        &self,
+        trait_id: TraitId<I>,
+    ) -> Vec<Arc<crate::rust_ir::DefaultMethodDatum<I>>> {
+        self.db.default_methods(trait_id)
+    }
+
     fn trait_datum(&self, trait_id: TraitId<I>) -> Arc<TraitDatum<I>> {
         self.db.trait_datum(trait_id)
     }
@@ -403,6 +454,10 @@ where
         self.db.adt_datum(adt_id)
     }
 
+    fn adt_ids(&self) -> Vec<AdtId<I>> {
+        self.db.adt_ids()
+    }
+
     fn generator_datum(&self, generator_id: GeneratorId<I>) -> Arc<GeneratorDatum<I>> {
         self.db.borrow().generator_datum(generator_id)
     }
@@ -455,6 +510,10 @@ where
         self.db.impl_provided_for(auto_trait_id, ty)
     }
 
+    fn impls_provided_for(&self, auto_trait_id: TraitId<I>, ty: &TyKind<I>) -> Vec<ImplId<I>> {
+        self.db.impls_provided_for(auto_trait_id, ty)
+    }
+
     fn well_known_trait_id(
         &self,
         well_known_trait: crate::rust_ir::WellKnownTrait,
@@ -493,6 +552,10 @@ where
         self.db.assoc_type_name(assoc_ty_id)
     }
 
+    fn assoc_const_name(&self, assoc_const_id: AssocConstId<I>) -> String {
+        self.db.assoc_const_name(assoc_const_id)
+    }
+
     fn opaque_type_name(&self, opaque_ty_id: OpaqueTyId<I>) -> String {
         self.db.opaque_type_name(opaque_ty_id)
     }
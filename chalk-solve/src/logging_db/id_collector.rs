@@ -67,6 +67,13 @@ pub fn collect_unrecorded_ids<'i, I: Interner, DB: RustIrDatabase<I>>(
                         .visit_with(&mut collector, DebruijnIndex::INNERMOST);
                     assoc_ty_datum.visit_with(&mut collector, DebruijnIndex::INNERMOST);
                 }
+                for assoc_const_id in &trait_datum.associated_const_ids {
+                    collector
+                        .db
+                        .associated_const_data(*assoc_const_id)
+                        .ty
+                        .visit_with(&mut collector, DebruijnIndex::INNERMOST);
+                }
             }
             RecordedItemId::OpaqueTy(opaque_id) => {
                 collector
@@ -152,7 +159,9 @@ impl<'i, I: Interner, DB: RustIrDatabase<I>> Visitor<I> for IdCollector<'i, I, D
         outer_binder: DebruijnIndex,
     ) -> ControlFlow<()> {
         match where_clause {
-            WhereClause::Implemented(trait_ref) => self.record(trait_ref.trait_id),
+            WhereClause::Implemented(trait_ref) | WhereClause::NotImplemented(trait_ref) => {
+                self.record(trait_ref.trait_id)
+            }
             WhereClause::AliasEq(alias_eq) => self.visit_alias(&alias_eq.alias),
             WhereClause::LifetimeOutlives(_lifetime_outlives) => (),
             WhereClause::TypeOutlives(_type_outlives) => (),
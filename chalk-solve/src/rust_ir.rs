@@ -2,14 +2,16 @@
 //! version of the AST, roughly corresponding to [the HIR] in the Rust
 //! compiler.
 
+use crate::RustIrDatabase;
 use chalk_derive::{Fold, HasInterner, Visit};
 use chalk_ir::cast::Cast;
 use chalk_ir::fold::shift::Shift;
 use chalk_ir::interner::Interner;
 use chalk_ir::{
-    try_break, visit::Visit, AdtId, AliasEq, AliasTy, AssocTypeId, Binders, DebruijnIndex, FnDefId,
-    GenericArg, ImplId, OpaqueTyId, ProjectionTy, QuantifiedWhereClause, Substitution,
-    ToGenericArg, TraitId, TraitRef, Ty, TyKind, VariableKind, WhereClause, WithKind,
+    try_break, visit::Visit, AdtId, AliasEq, AliasTy, AssocConstId, AssocTypeId, Binders, Const,
+    DebruijnIndex, DomainGoal, FnDefId, FromEnv, GenericArg, Goal, GoalData, ImplId, OpaqueTyId,
+    ProgramClause, ProgramClauses, ProjectionTy, QuantifiedWhereClause, QuantifierKind,
+    Substitution, ToGenericArg, TraitId, TraitRef, Ty, TyKind, VariableKind, WhereClause, WithKind,
 };
 use std::iter;
 use std::ops::ControlFlow;
@@ -27,6 +29,7 @@ pub struct ImplDatum<I: Interner> {
     pub binders: Binders<ImplDatumBound<I>>,
     pub impl_type: ImplType,
     pub associated_ty_value_ids: Vec<AssociatedTyValueId<I>>,
+    pub associated_const_values: Vec<AssociatedConstValue<I>>,
 }
 
 impl<I: Interner> ImplDatum<I> {
@@ -38,6 +41,18 @@ impl<I: Interner> ImplDatum<I> {
         self.binders.skip_binders().trait_ref.trait_id
     }
 
+    /// Whether this impl gives a value for the associated type
+    /// `associated_ty_id`. If it doesn't, the trait's default applies.
+    pub fn provides_associated_ty(
+        &self,
+        db: &dyn RustIrDatabase<I>,
+        associated_ty_id: AssocTypeId<I>,
+    ) -> bool {
+        self.associated_ty_value_ids
+            .iter()
+            .any(|&atv_id| db.associated_ty_value(atv_id).associated_ty_id == associated_ty_id)
+    }
+
     pub fn self_type_adt_id(&self, interner: I) -> Option<AdtId<I>> {
         match self
             .binders
@@ -235,6 +250,8 @@ pub struct TraitDatum<I: Interner> {
 
     pub associated_ty_ids: Vec<AssocTypeId<I>>,
 
+    pub associated_const_ids: Vec<AssocConstId<I>>,
+
     /// If this is a well-known trait, which one? If `None`, this is a regular,
     /// user-defined trait.
     pub well_known: Option<WellKnownTrait>,
@@ -259,6 +276,8 @@ pub enum WellKnownTrait {
     CoerceUnsized,
     DiscriminantKind,
     Generator,
+    /// The trait `Tuple`, implemented by every tuple type (including `()`).
+    Tuple,
 }
 
 chalk_ir::const_visit!(WellKnownTrait);
@@ -298,7 +317,11 @@ pub struct TraitDatumBound<I: Interner> {
     pub where_clauses: Vec<QuantifiedWhereClause<I>>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// The flags of a trait. New flags may be added, so outside of this crate
+/// start from `TraitFlags::default()` (with every flag unset) and set the
+/// flags you need.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct TraitFlags {
     /// An "auto trait" is one that is "automatically implemented" for every
     /// struct, so long as no explicit impl is given.
@@ -329,6 +352,11 @@ pub struct TraitFlags {
     pub non_enumerable: bool,
 
     pub coinductive: bool,
+
+    /// A trait alias, e.g. `trait Printable = Display + Debug;`. Its bounds
+    /// are its where clauses, and instead of having impls it is implemented
+    /// for every type that satisfies them.
+    pub alias: bool,
 }
 
 chalk_ir::const_visit!(TraitFlags);
@@ -511,6 +539,10 @@ pub struct AssociatedTyDatumBound<I: Interner> {
 
     /// Where clauses that must hold for the projection to be well-formed.
     pub where_clauses: Vec<QuantifiedWhereClause<I>>,
+
+    /// The default value given in the trait, e.g. `u32` in `type Item = u32;`.
+    /// It is used by impls that don't provide a value of their own.
+    pub default: Option<Ty<I>>,
 }
 
 impl<I: Interner> AssociatedTyDatum<I> {
@@ -608,6 +640,119 @@ pub struct AssociatedTyValueBound<I: Interner> {
     pub ty: Ty<I>,
 }
 
+/// Represents an associated const declaration found inside of a trait:
+///
+/// ```notrust
+/// trait Foo<P1..Pn> { // P0 is Self
+///     const N: T;
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssociatedConstDatum<I: Interner> {
+    /// The trait this associated const is defined in.
+    pub trait_id: TraitId<I>,
+
+    /// The ID of this associated const
+    pub id: AssocConstId<I>,
+
+    /// Name of this associated const.
+    pub name: I::Identifier,
+
+    /// The type of the const. The binders represent the trait's
+    /// parameters `P0...Pn`.
+    pub ty: Binders<Ty<I>>,
+}
+
+/// Represents the *value* of an associated const that is assigned
+/// from within some impl.
+///
+/// ```ignore
+/// impl Foo for Bar {
+///     const N = 3; // <-- represents this line!
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Fold, Visit)]
+pub struct AssociatedConstValue<I: Interner> {
+    /// Impl in which this associated const value is found.
+    pub impl_id: ImplId<I>,
+
+    /// Associated const being defined.
+    pub associated_const_id: AssocConstId<I>,
+
+    /// The value of the const. The binders are those of the impl.
+    pub value: Binders<Const<I>>,
+}
+
+/// Represents a method with a default body in a trait. Only what is needed
+/// to solve goals inside the body is recorded:
+///
+/// ```ignore
+/// trait Foo<P1..Pn> { // P0 is Self
+///     fn bar<Q1..Qm>() where WC { .. }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DefaultMethodDatum<I: Interner> {
+    /// The trait this method is defined in.
+    pub trait_id: TraitId<I>,
+
+    /// Name of this method.
+    pub name: I::Identifier,
+
+    /// The binders represent the method's parameters `Q1..Qm` followed by
+    /// the trait's parameters `P0..Pn`, as for associated types.
+    pub binders: Binders<DefaultMethodDatumBound<I>>,
+}
+
+/// Encodes the parts of `DefaultMethodDatum` where the parameters
+/// `Q1..Qm, P0..Pn` are in scope.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Fold, Visit, HasInterner)]
+pub struct DefaultMethodDatumBound<I: Interner> {
+    /// `Self: Foo<P1..Pn>`, which holds inside the body.
+    pub trait_ref: TraitRef<I>,
+
+    /// The method's own where clauses.
+    pub where_clauses: Vec<QuantifiedWhereClause<I>>,
+}
+
+impl<I: Interner> DefaultMethodDatum<I> {
+    /// Wraps `goal`, whose bound variables refer to the method's binders,
+    /// into a closed goal that holds if `goal` is provable inside the
+    /// method's body:
+    ///
+    /// ```notrust
+    /// forall<Q1..Qm, P0..Pn> {
+    ///     if (FromEnv(Self: Foo<P1..Pn>), FromEnv(WC)) { goal }
+    /// }
+    /// ```
+    pub fn goal_in_body(&self, interner: I, goal: Goal<I>) -> Goal<I> {
+        let DefaultMethodDatumBound {
+            trait_ref,
+            where_clauses,
+        } = self.binders.skip_binders();
+
+        let environment = iter::once(
+            DomainGoal::FromEnv(FromEnv::Trait(trait_ref.clone()))
+                .cast::<ProgramClause<I>>(interner),
+        )
+        .chain(
+            where_clauses
+                .iter()
+                .cloned()
+                .map(|wc| wc.into_from_env_goal(interner).cast(interner)),
+        );
+        let goal: Goal<I> =
+            GoalData::Implies(ProgramClauses::from_iter(interner, environment), goal)
+                .intern(interner);
+
+        GoalData::Quantified(
+            QuantifierKind::ForAll,
+            Binders::new(self.binders.binders.clone(), goal),
+        )
+        .intern(interner)
+    }
+}
+
 /// Represents the bounds for an `impl Trait` type.
 ///
 /// ```ignore
@@ -1,12 +1,17 @@
 use crate::RustIrDatabase;
 use chalk_derive::HasInterner;
+use chalk_ir::cast::Cast;
 use chalk_ir::interner::Interner;
 use chalk_ir::*;
 use std::fmt;
+use std::iter;
 use tracing::debug;
 
+mod cache;
 pub mod truncate;
 
+pub use cache::Cache;
+
 /// A (possible) solution for a proposed goal.
 #[derive(Clone, Debug, PartialEq, Eq, HasInterner)]
 pub enum Solution<I: Interner> {
@@ -20,6 +25,17 @@ pub enum Solution<I: Interner> {
     /// constraints, since we have not "committed" to any particular solution
     /// yet.
     Ambig(Guidance<I>),
+
+    /// The solver hit its overflow depth before it could decide whether the
+    /// goal holds. This is distinct from "no solution": the goal may well be
+    /// provable with a larger limit. For type inference purposes it carries
+    /// no guidance, like `Ambig(Guidance::Unknown)`.
+    ///
+    /// Only the recursive solver reports this. The SLG solver has no depth
+    /// limit; goals that exceed its `max_size` are truncated, and the
+    /// resulting answers are simply ambiguous. So the same goal can give
+    /// `Overflow` with one solver and `Ambig` with the other.
+    Overflow,
 }
 
 /// When a goal holds ambiguously (e.g., because there are multiple possible
@@ -74,6 +90,11 @@ impl<I: Interner> Solution<I> {
             other.display(interner)
         );
 
+        // If either side gave up, so do we:
+        if self.is_overflow() || other.is_overflow() {
+            return Solution::Overflow;
+        }
+
         // Otherwise, always downgrade to Ambig:
 
         let guidance = match (self.into_guidance(), other.into_guidance()) {
@@ -96,6 +117,7 @@ impl<I: Interner> Solution<I> {
                 binders: constrained.binders,
             }),
             Solution::Ambig(guidance) => guidance,
+            Solution::Overflow => Guidance::Unknown,
         }
     }
 
@@ -114,7 +136,7 @@ impl<I: Interner> Solution<I> {
                     binders: canonical.binders.clone(),
                 })
             }
-            Solution::Ambig(_) => None,
+            Solution::Ambig(_) | Solution::Overflow => None,
         }
     }
 
@@ -145,6 +167,10 @@ impl<I: Interner> Solution<I> {
         matches!(*self, Solution::Ambig(_))
     }
 
+    pub fn is_overflow(&self) -> bool {
+        matches!(*self, Solution::Overflow)
+    }
+
     pub fn display<'a>(&'a self, interner: I) -> SolutionDisplay<'a, I> {
         SolutionDisplay {
             solution: self,
@@ -184,6 +210,7 @@ impl<'a, I: Interner> fmt::Display for SolutionDisplay<'a, I> {
                 subst.display(*interner)
             ),
             Solution::Ambig(Guidance::Unknown) => write!(f, "Ambiguous; no inference guidance"),
+            Solution::Overflow => write!(f, "Overflow"),
         }
     }
 }
@@ -222,6 +249,27 @@ impl<S: fmt::Display> fmt::Display for SubstitutionResult<S> {
     }
 }
 
+/// Simplifies the goal of a query (see `Goal::simplify`). The solvers do
+/// this to every goal they are given, so that they don't spend any work on
+/// subgoals that are trivially true. The canonical variables of the query are
+/// left as they are, so its solutions are unaffected.
+pub fn simplify_canonical_goal<I: Interner>(
+    interner: I,
+    goal: &UCanonical<InEnvironment<Goal<I>>>,
+) -> UCanonical<InEnvironment<Goal<I>>> {
+    let InEnvironment {
+        environment,
+        goal: inner_goal,
+    } = &goal.canonical.value;
+    UCanonical {
+        canonical: Canonical {
+            binders: goal.canonical.binders.clone(),
+            value: InEnvironment::new(environment, inner_goal.simplify(interner)),
+        },
+        universes: goal.universes,
+    }
+}
+
 /// Finds the solution to "goals", or trait queries -- i.e., figures
 /// out what sets of types implement which traits. Also, between
 /// queries, this struct stores the cached state from previous solver
@@ -324,4 +372,101 @@ where
             None => false,
         }
     }
+
+    /// Solves each of `goals` in turn, returning their solutions in the same
+    /// order. Like repeated calls to `solve`, work done for earlier goals
+    /// (e.g. for subgoals they have in common) is cached and reused for
+    /// later ones.
+    fn solve_all(
+        &mut self,
+        program: &dyn RustIrDatabase<I>,
+        goals: &[UCanonical<InEnvironment<Goal<I>>>],
+    ) -> Vec<Option<Solution<I>>> {
+        goals.iter().map(|goal| self.solve(program, goal)).collect()
+    }
+
+    /// Solves `goal` as if `assumptions` held in addition to its environment,
+    /// i.e. as if the goal were `if (assumptions) { goal }`. The assumptions
+    /// are expressed in terms of the goal's canonical variables and universes;
+    /// they are added as `FromEnv` clauses to a copy of the environment, where
+    /// they are elaborated like any other environment clause (see
+    /// `program_clauses_for_env`).
+    fn solve_with_assumptions(
+        &mut self,
+        program: &dyn RustIrDatabase<I>,
+        goal: &UCanonical<InEnvironment<Goal<I>>>,
+        assumptions: &[ProgramClause<I>],
+    ) -> Option<Solution<I>> {
+        let interner = program.interner();
+        let mut goal = goal.clone();
+        let environment = &mut goal.canonical.value.environment;
+        *environment = environment.clone().add_clauses(
+            interner,
+            assumptions
+                .iter()
+                .map(|assumption| assumption.clone().into_from_env_clause(interner)),
+        );
+        self.solve(program, &goal)
+    }
+
+    /// Like `solve`, but applies the fallback for integer and float
+    /// literals when the solution is ambiguous: variables of integer kind
+    /// default to `i32` and variables of float kind to `f64`. As in rustc,
+    /// the defaults are only tried once nothing else has resolved the
+    /// goal, and they are only kept if the goal then has a unique solution.
+    fn solve_with_numeric_fallback(
+        &mut self,
+        program: &dyn RustIrDatabase<I>,
+        goal: &UCanonical<InEnvironment<Goal<I>>>,
+    ) -> Option<Solution<I>> {
+        let solution = self.solve(program, goal)?;
+        if !solution.is_ambig() {
+            return Some(solution);
+        }
+
+        let interner = program.interner();
+        let defaults: Vec<Goal<I>> = goal
+            .canonical
+            .binders
+            .iter(interner)
+            .enumerate()
+            .filter_map(|(i, var)| {
+                let default = match var.kind {
+                    VariableKind::Ty(TyVariableKind::Integer) => Scalar::Int(IntTy::I32),
+                    VariableKind::Ty(TyVariableKind::Float) => Scalar::Float(FloatTy::F64),
+                    _ => return None,
+                };
+                let var = BoundVar::new(DebruijnIndex::INNERMOST, i).to_ty(interner);
+                Some(
+                    EqGoal {
+                        a: var.cast(interner),
+                        b: TyKind::Scalar(default).intern(interner).cast(interner),
+                    }
+                    .cast(interner),
+                )
+            })
+            .collect();
+        if defaults.is_empty() {
+            return Some(solution);
+        }
+
+        let InEnvironment {
+            environment,
+            goal: inner_goal,
+        } = &goal.canonical.value;
+        let defaulted_goal = UCanonical {
+            canonical: Canonical {
+                binders: goal.canonical.binders.clone(),
+                value: InEnvironment::new(
+                    environment,
+                    Goal::all(interner, iter::once(inner_goal.clone()).chain(defaults)),
+                ),
+            },
+            universes: goal.universes,
+        };
+        match self.solve(program, &defaulted_goal) {
+            Some(defaulted) if defaulted.is_unique() => Some(defaulted),
+            _ => Some(solution),
+        }
+    }
 }
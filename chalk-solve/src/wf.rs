@@ -18,6 +18,7 @@ pub enum WfError<I: Interner> {
     IllFormedTypeDecl(chalk_ir::AdtId<I>),
     IllFormedOpaqueTypeDecl(chalk_ir::OpaqueTyId<I>),
     IllFormedTraitImpl(chalk_ir::TraitId<I>),
+    CopyImplNeedsDrop(chalk_ir::ImplId<I>),
 }
 
 impl<I: Interner> fmt::Display for WfError<I> {
@@ -38,6 +39,11 @@ impl<I: Interner> fmt::Display for WfError<I> {
                 "trait impl for `{:?}` does not meet well-formedness requirements",
                 id
             ),
+            WfError::CopyImplNeedsDrop(id) => write!(
+                f,
+                "`Copy` impl `{:?}` is for a type that needs drop glue",
+                id
+            ),
         }
     }
 }
@@ -90,7 +96,9 @@ impl<I: Interner> Visitor<I> for InputTypeCollector<I> {
                 .clone()
                 .intern(self.interner)
                 .visit_with(self, outer_binder),
-            WhereClause::Implemented(trait_ref) => trait_ref.visit_with(self, outer_binder),
+            WhereClause::Implemented(trait_ref) | WhereClause::NotImplemented(trait_ref) => {
+                trait_ref.visit_with(self, outer_binder)
+            }
             WhereClause::TypeOutlives(TypeOutlives { ty, .. }) => ty.visit_with(self, outer_binder),
             WhereClause::LifetimeOutlives(..) => ControlFlow::Continue(()),
         }
@@ -343,6 +351,86 @@ where
         }
     }
 
+    /// Returns the obligations checked by `verify_trait_impl` that cannot be
+    /// proven, one closed goal per obligation. Each goal quantifies over the
+    /// impl's parameters and assumes the impl's environment, so it can be
+    /// reported as is. The builtin checks for well-known traits are not
+    /// included.
+    pub fn unsatisfied_impl_obligations(&self, impl_id: ImplId<I>) -> Vec<Goal<I>> {
+        let interner = self.db.interner();
+
+        impl_obligation_goals(self.db, impl_id)
+            .into_iter()
+            .filter(|goal| {
+                debug!("WF impl obligation: {:?}", goal);
+                let mut fresh_solver = (self.solver_builder)();
+                !fresh_solver.has_unique_solution(self.db, &goal.clone().into_closed_goal(interner))
+            })
+            .collect()
+    }
+
+    /// Checks that no type with a `Copy` impl needs drop glue, i.e. that
+    /// neither the type nor any type it contains by value implements `Drop`.
+    /// Returns an error for each conflicting impl; if either lang item is
+    /// missing there is nothing to check.
+    pub fn check_copy_drop_conflict(&self) -> Vec<WfError<I>> {
+        let interner = self.db.interner();
+
+        let (copy_trait_id, drop_trait_id) = match (
+            self.db.well_known_trait_id(WellKnownTrait::Copy),
+            self.db.well_known_trait_id(WellKnownTrait::Drop),
+        ) {
+            (Some(copy_trait_id), Some(drop_trait_id)) => (copy_trait_id, drop_trait_id),
+            _ => return vec![],
+        };
+
+        self.db
+            .local_impls_to_coherence_check(copy_trait_id)
+            .into_iter()
+            .filter(|&impl_id| {
+                let impl_datum = self.db.impl_datum(impl_id);
+                if !impl_datum.is_positive() {
+                    return false;
+                }
+
+                let impl_fields = impl_datum
+                    .binders
+                    .map_ref(|v| (v.trait_ref.clone(), v.where_clauses.clone()));
+
+                let mut gb = GoalBuilder::new(self.db);
+                // forall<P..> { if (WC) { all(not { Ty: Drop }) } }
+                let goal = gb.forall(
+                    &impl_fields,
+                    drop_trait_id,
+                    |gb, _, (trait_ref, where_clauses), drop_trait_id| {
+                        let interner = gb.interner();
+                        let mut tys = vec![];
+                        drop_glue_tys(gb.db(), trait_ref.self_type_parameter(interner), &mut tys);
+                        gb.implies(
+                            impl_wf_environment(interner, where_clauses, trait_ref),
+                            |gb| {
+                                gb.all(tys.into_iter().map(|ty| {
+                                    TraitRef {
+                                        trait_id: drop_trait_id,
+                                        substitution: Substitution::from1(interner, ty),
+                                    }
+                                    .cast::<Goal<I>>(interner)
+                                    .negate(interner)
+                                }))
+                            },
+                        )
+                    },
+                );
+
+                debug!("Copy/Drop goal: {:?}", goal);
+
+                let mut fresh_solver = (self.solver_builder)();
+                !fresh_solver.has_unique_solution(self.db, &goal.into_closed_goal(interner))
+            })
+            .map(WfError::CopyImplNeedsDrop)
+            .collect()
+    }
+
     pub fn verify_opaque_ty_decl(&self, opaque_ty_id: OpaqueTyId<I>) -> Result<(), WfError<I>> {
         // Given an opaque type like
         // ```notrust
@@ -428,7 +516,8 @@ where
             | WellKnownTrait::Unsize
             | WellKnownTrait::Sized
             | WellKnownTrait::DiscriminantKind
-            | WellKnownTrait::Generator => false,
+            | WellKnownTrait::Generator
+            | WellKnownTrait::Tuple => false,
         };
 
         if is_legal {
@@ -488,6 +577,100 @@ fn impl_header_wf_goal<I: Interner>(
     Some(well_formed_goal)
 }
 
+/// Splits the goal built by `impl_header_wf_goal` (and the associated type
+/// value goals) into separate obligations. `WellFormed(TraitRef)` is
+/// expanded into the trait's where clauses, so that a missing bound is
+/// reported as the where clause that needs it.
+fn impl_obligation_goals<I: Interner>(
+    db: &dyn RustIrDatabase<I>,
+    impl_id: ImplId<I>,
+) -> Vec<Goal<I>> {
+    let interner = db.interner();
+    let impl_datum = db.impl_datum(impl_id);
+
+    if !impl_datum.is_positive() {
+        return Vec::new();
+    }
+
+    let trait_datum = db.trait_datum(impl_datum.trait_id());
+
+    let (impl_bound, impl_binders) = impl_datum.binders.clone().into_value_and_skipped_binders();
+    let trait_ref = &impl_bound.trait_ref;
+    let where_clauses = &impl_bound.where_clauses;
+
+    let environment = ProgramClauses::from_iter(
+        interner,
+        impl_wf_environment(interner, where_clauses, trait_ref),
+    );
+
+    let input_types_wf = InputTypeCollector::types_in(interner, where_clauses)
+        .into_iter()
+        .map(|ty| ty.well_formed().cast(interner));
+    let trait_where_clauses_wf = trait_datum
+        .binders
+        .map_ref(|bound| bound.where_clauses.clone())
+        .substitute(interner, &trait_ref.substitution)
+        .into_iter()
+        .map(|wc| wc.into_well_formed_goal(interner).cast(interner));
+
+    // forall<P0...Pn> { if (WC && input types are well formed) { obligation } }
+    let header_goals = input_types_wf
+        .chain(trait_where_clauses_wf)
+        .map(|goal: Goal<I>| {
+            let goal = GoalData::Implies(environment.clone(), goal).intern(interner);
+            GoalData::Quantified(
+                QuantifierKind::ForAll,
+                Binders::new(impl_binders.clone(), goal),
+            )
+            .intern(interner)
+        });
+
+    header_goals
+        .chain(
+            impl_datum
+                .associated_ty_value_ids
+                .iter()
+                .filter_map(|&id| compute_assoc_ty_goal(db, id)),
+        )
+        .collect()
+}
+
+/// Collects `ty` and every type it contains by value (ADT fields, tuple and
+/// array elements), i.e. the types whose `Drop` impls would give `ty` drop
+/// glue.
+fn drop_glue_tys<I: Interner>(db: &dyn RustIrDatabase<I>, ty: Ty<I>, tys: &mut Vec<Ty<I>>) {
+    let interner = db.interner();
+
+    if tys.contains(&ty) {
+        return;
+    }
+
+    match ty.kind(interner) {
+        TyKind::Adt(adt_id, substitution) => {
+            let fields: Vec<_> = db
+                .adt_datum(*adt_id)
+                .binders
+                .map_ref(|b| &b.variants)
+                .cloned()
+                .substitute(interner, substitution)
+                .into_iter()
+                .flat_map(|v| v.fields)
+                .collect();
+            tys.push(ty);
+            for field in fields {
+                drop_glue_tys(db, field, tys);
+            }
+        }
+        TyKind::Tuple(_, substitution) => {
+            for field in substitution.iter(interner).filter_map(|p| p.ty(interner)) {
+                drop_glue_tys(db, field.clone(), tys);
+            }
+        }
+        TyKind::Array(elem, _) => drop_glue_tys(db, elem.clone(), tys),
+        _ => {}
+    }
+}
+
 /// Creates the conditions that an impl (and its contents of an impl)
 /// can assume to be true when proving that it is well-formed.
 fn impl_wf_environment<'i, I: Interner>(
@@ -608,6 +791,7 @@ fn compute_assoc_ty_goal<I: Interner>(
                 let AssociatedTyDatumBound {
                     bounds: defn_bounds,
                     where_clauses: defn_where_clauses,
+                    ..
                 } = assoc_ty_datum
                     .binders
                     .clone()
@@ -10,6 +10,7 @@ use chalk_integration::interner::ChalkIr;
 use chalk_integration::lowering::*;
 use chalk_integration::query::LoweringDatabase;
 use chalk_integration::SolverChoice;
+use chalk_solve::display::{write_program_clause, WriterState};
 use chalk_solve::ext::*;
 use chalk_solve::logging;
 use chalk_solve::RustIrDatabase;
@@ -98,11 +99,31 @@ impl LoadedProgram {
         } else {
             match self.db.solve(&peeled_goal) {
                 Some(v) => println!("{}\n", v.display(ChalkIr)),
-                None => println!("No possible solution.\n"),
+                None => {
+                    println!("No possible solution.\n");
+                    if let Some(error) = self.db.unproductive_cycle(&peeled_goal) {
+                        println!("note: {}\n", error);
+                    }
+                }
             }
         }
         Ok(())
     }
+
+    /// Parse a goal and print the program clauses that could be used to
+    /// prove it.
+    fn clauses(&self, text: &str) -> Result<()> {
+        let program = self.db.checked_program()?;
+        let goal = lower_goal(&*chalk_parse::parse_goal(text)?, &program)?;
+        let peeled_goal = goal.into_peeled_goal(self.db.interner());
+        let ws = WriterState::<_, ChalkDatabase, _>::new(&self.db);
+        for clause in self.db.program_clauses_for_goal(&peeled_goal)? {
+            let mut out = String::new();
+            write_program_clause(&mut out, &ws, &clause)?;
+            println!("{}", out);
+        }
+        Ok(())
+    }
 }
 
 fn run() -> Result<()> {
@@ -224,7 +245,7 @@ fn process(
             None => println!("debug <level> set debug level to <level>"),
         }
     } else {
-        // The command is either "print", "lowered", or a goal.
+        // The command is either "print", "lowered", "clauses", or a goal.
 
         // Check that a program has been loaded.
         let prog = prog
@@ -240,6 +261,11 @@ fn process(
                 // TODO: Write a line of documentation here.
                 "lowered" => println!("{:#?}", prog.db.environment()),
 
+                // Print out the program clauses for a goal.
+                _ if command.starts_with("clauses ") => {
+                    prog.clauses(&command["clauses ".len()..])?
+                }
+
                 // Assume this is a goal.
                 // TODO: Print out "type 'help' to see available commands" if it
                 // fails to parse?
@@ -265,13 +291,14 @@ fn load_program(args: &Args, filename: &str) -> Result<LoadedProgram> {
 // TODO: Implement "help <command>" for more info.
 fn help() {
     println!("Commands:");
-    println!("  help          print this output");
-    println!("  program       provide a program via stdin");
-    println!("  load <file>   load program from <file>");
-    println!("  print         print the current program");
-    println!("  lowered       print the lowered program");
-    println!("  <goal>        attempt to solve <goal>");
-    println!("  debug <level> set debug level to <level>");
+    println!("  help            print this output");
+    println!("  program         provide a program via stdin");
+    println!("  load <file>     load program from <file>");
+    println!("  print           print the current program");
+    println!("  lowered         print the lowered program");
+    println!("  clauses <goal>  print the program clauses for <goal>");
+    println!("  <goal>          attempt to solve <goal>");
+    println!("  debug <level>   set debug level to <level>");
 }
 
 /// Read a program from the command-line. Stop reading when EOF is read. If
@@ -218,6 +218,24 @@ fn test_assoc_type_where_clause_referencing_trait_generics() {
     );
 }
 
+#[test]
+fn test_assoc_type_default() {
+    // Test that defaults render correctly, including ones referencing both
+    // trait and associated type generics.
+    reparse_test!(
+        program {
+            struct Foo { }
+            struct Floo<A, B> { }
+            trait Bax {}
+            trait Bar<T> {
+                type Assoc = Foo;
+                type Assoc2<Y> where Y: Bax = Floo<T, Y>;
+            }
+            impl Bar<Foo> for Foo { }
+        }
+    );
+}
+
 #[test]
 fn test_assoc_type_and_trait_generics_coexist() {
     // Test that we give associated type generics and trait generics different
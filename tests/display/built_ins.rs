@@ -283,3 +283,21 @@ fn test_array_types() {
         }
     );
 }
+
+#[test]
+fn test_error_type() {
+    // Test that we print the error type as `{error}` and can read it back.
+    reparse_test!(
+        program {
+            struct Foo {
+                field: {error}
+            }
+            trait Bar {
+                type Baz;
+            }
+            impl Bar for Foo {
+                type Baz = {error};
+            }
+        }
+    );
+}
@@ -58,3 +58,25 @@ fn test_basic_const_values_in_assoc_ty_values() {
         }
     );
 }
+
+#[test]
+fn test_assoc_consts() {
+    // Test we render associated consts and their values, alongside associated
+    // types and using the generics of the trait and impl.
+    reparse_test!(
+        program {
+            struct Baz { }
+            struct Bar<const C> { }
+            trait Foo<T> {
+                type Assoc;
+                const N: T;
+                const M: usize;
+            }
+            impl<const C> Foo<usize> for Bar<C> {
+                type Assoc = Baz;
+                const N = C;
+                const M = 3;
+            }
+        }
+    );
+}
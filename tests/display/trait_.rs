@@ -108,3 +108,19 @@ fn test_lang_with_flag() {
         }
     );
 }
+
+#[test]
+fn test_trait_alias() {
+    // Test we print trait aliases as aliases, with their bounds and
+    // parameters.
+    reparse_test!(
+        program {
+            trait Display { }
+            trait Into<T> { }
+            trait Iterator { type Item; }
+            trait Printable = Display;
+            trait IntoIterOf<T> = Iterator<Item = T> + Into<T>;
+            trait Borrowing = forall<'a> Into<&'a u32>;
+        }
+    );
+}
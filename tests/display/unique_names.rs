@@ -49,6 +49,9 @@ where
     fn assoc_type_name(&self, _assoc_ty_id: chalk_ir::AssocTypeId<I>) -> String {
         "Foo".to_owned()
     }
+    fn assoc_const_name(&self, _assoc_const_id: chalk_ir::AssocConstId<I>) -> String {
+        "Foo".to_owned()
+    }
     fn opaque_type_name(&self, _opaque_ty_id: chalk_ir::OpaqueTyId<I>) -> String {
         "Foo".to_owned()
     }
@@ -64,6 +67,18 @@ where
     ) -> std::sync::Arc<chalk_solve::rust_ir::AssociatedTyDatum<I>> {
         self.db.associated_ty_data(ty)
     }
+    fn associated_const_data(
+        &self,
+        id: chalk_ir::AssocConstId<I>,
+    ) -> std::sync::Arc<chalk_solve::rust_ir::AssociatedConstDatum<I>> {
+        self.db.associated_const_data(id)
+    }
+    fn default_methods(
+        &self,
+        trait_id: chalk_ir::TraitId<I>,
+    ) -> Vec<std::sync::Arc<chalk_solve::rust_ir::DefaultMethodDatum<I>>> {
+        self.db.default_methods(trait_id)
+    }
     fn trait_datum(
         &self,
         trait_id: chalk_ir::TraitId<I>,
@@ -76,6 +91,9 @@ where
     ) -> std::sync::Arc<chalk_solve::rust_ir::AdtDatum<I>> {
         self.db.adt_datum(adt_id)
     }
+    fn adt_ids(&self) -> Vec<chalk_ir::AdtId<I>> {
+        self.db.adt_ids()
+    }
     fn adt_repr(&self, id: chalk_ir::AdtId<I>) -> Arc<chalk_solve::rust_ir::AdtRepr<I>> {
         self.db.adt_repr(id)
     }
@@ -139,6 +157,13 @@ where
     ) -> bool {
         self.db.impl_provided_for(auto_trait_id, app_ty)
     }
+    fn impls_provided_for(
+        &self,
+        auto_trait_id: chalk_ir::TraitId<I>,
+        app_ty: &chalk_ir::TyKind<I>,
+    ) -> Vec<chalk_ir::ImplId<I>> {
+        self.db.impls_provided_for(auto_trait_id, app_ty)
+    }
     fn well_known_trait_id(
         &self,
         well_known_trait: chalk_solve::rust_ir::WellKnownTrait,
@@ -184,6 +184,20 @@ fn test_impl_where_clauses() {
     // TODO: more of these
 }
 
+#[test]
+fn test_negative_where_clauses() {
+    reparse_test!(
+        program {
+            #[auto]
+            trait Send { }
+            trait Bez { }
+            struct Foo<T> where T: !Send { }
+            impl<T> Bez for Foo<T> where T: !Send { }
+            fn bar<T>() where T: !Send;
+        }
+    );
+}
+
 #[test]
 fn test_trait_projection() {
     reparse_test!(
@@ -0,0 +1,46 @@
+use super::util::db_and_goal;
+use chalk_engine::solve::SLGSolver;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::SolverChoice;
+
+const PROGRAM: &str = "
+    trait Foo { }
+    trait Bar { }
+    struct Z { }
+    struct S<T> { }
+    impl Foo for Z { }
+    impl<T> Foo for S<T> where T: Foo { }
+";
+
+/// The first `n` answers to `goal_text`, formatted like the `yields_all`
+/// expectations in the `test!` macro.
+fn first_answers(goal_text: &str, n: usize) -> Vec<String> {
+    let (db, goal) = db_and_goal(PROGRAM, SolverChoice::slg_default(), goal_text);
+    let mut solver = SLGSolver::new(50, None, None);
+    db.with_program(|_| {
+        solver
+            .answers(&db, &goal)
+            .take(n)
+            .map(|answer| format!("{}", answer.as_ref().map(|v| v.display(ChalkIr))))
+            .collect()
+    })
+}
+
+#[test]
+fn answers_stop_early_for_infinitely_many_solutions() {
+    assert_eq!(
+        first_answers("exists<T> { T: Foo }", 3),
+        [
+            "substitution [?0 := Z]",
+            "substitution [?0 := S<Z>]",
+            "substitution [?0 := S<S<Z>>]",
+        ]
+    );
+}
+
+#[test]
+fn answers_end_after_the_last_solution() {
+    // A goal without variables has (at most) the empty substitution.
+    assert_eq!(first_answers("S<Z>: Foo", 10), [""]);
+    assert!(first_answers("Z: Bar", 10).is_empty());
+}
@@ -0,0 +1,60 @@
+//! Snapshot tests for the clauses returned by `program_clauses_for_goal`.
+//!
+//! `clause_dump` lowers a program and a goal and prints one clause per
+//! line, so the expected output can be written down as a plain string.
+
+use super::util::db_and_goal;
+use chalk_integration::SolverChoice;
+
+fn clause_dump(program_text: &str, goal_text: &str) -> String {
+    let (db, goal) = db_and_goal(program_text, SolverChoice::default(), goal_text);
+    db.with_program(|_| {
+        db.program_clauses_for_goal(&goal)
+            .unwrap()
+            .iter()
+            .map(|clause| format!("{:?}\n", clause))
+            .collect()
+    })
+}
+
+fn assert_clause_dump(program_text: &str, goal_text: &str, expected: &str) {
+    let actual = clause_dump(program_text, goal_text);
+    assert_eq!(
+        actual.trim(),
+        expected.trim(),
+        "clause dump differs, actual:\n{}",
+        actual
+    );
+}
+
+const ELABORATED_ENV: &str = "
+    trait A { }
+    trait B where Self: A { }
+    trait C where Self: B { }
+    trait D where Self: A { }
+    struct Foo { }
+    impl A for Foo { }
+";
+
+#[test]
+fn clause_dump_is_reproducible() {
+    let goal = "forall<T> { if (T: C; T: D) { T: A } }";
+    let first = clause_dump(ELABORATED_ENV, goal);
+    for _ in 0..10 {
+        assert_eq!(clause_dump(ELABORATED_ENV, goal), first);
+    }
+}
+
+#[test]
+fn elaborated_env_clauses_keep_generation_order() {
+    // `T: D` yields the rule for `A` in the first elaboration round, while
+    // `T: C` only reaches `B` (and from there `A`) in the second one.
+    assert_clause_dump(
+        ELABORATED_ENV,
+        "forall<T> { if (T: C; T: D) { FromEnv(T: A) } }",
+        "
+for<type> FromEnv(^0.0: A) :- FromEnv(^0.0: D)
+for<type> FromEnv(^0.0: A) :- FromEnv(^0.0: B)
+",
+    );
+}
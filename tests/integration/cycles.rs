@@ -0,0 +1,142 @@
+use super::util::closed_goal;
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::query::LoweringDatabase;
+use chalk_integration::SolverChoice;
+use chalk_solve::clauses::cycles::{check_unproductive_cycles, CycleError};
+
+/// The error reported by `check_unproductive_cycles`, if any.
+fn cycle_error(program_text: &str, goal_text: &str) -> Option<CycleError<ChalkIr>> {
+    let db = ChalkDatabase::with(program_text, SolverChoice::default());
+    db.unproductive_cycle(&closed_goal(&db, goal_text))
+}
+
+#[test]
+fn unproductive_inductive_cycle_is_reported() {
+    let program_text = "
+        trait A { }
+        trait B { }
+        struct X { }
+        impl A for X where X: B { }
+        impl B for X where X: A { }
+    ";
+    let db = ChalkDatabase::with(program_text, SolverChoice::default());
+    let program = db.program_ir().unwrap();
+    let goal = closed_goal(&db, "X: A");
+
+    // The solver on its own just fails to prove the goal.
+    let solution = db.solve(&goal);
+    assert!(solution.is_none(), "{:?}", solution);
+
+    let error = check_unproductive_cycles(&*program, &goal).unwrap_err();
+    db.with_program(|_| {
+        assert_eq!(
+            error.to_string(),
+            "unproductive cycle while proving `Implemented(X: A)` through 2 clause(s)"
+        );
+        let clauses: Vec<_> = error
+            .clauses
+            .iter()
+            .map(|clause| format!("{:?}", clause))
+            .collect();
+        assert_eq!(
+            clauses,
+            [
+                "for<> Implemented(X: A) :- ForAll<> { Implemented(X: B) }",
+                "for<> Implemented(X: B) :- ForAll<> { Implemented(X: A) }"
+            ]
+        );
+    });
+}
+
+#[test]
+fn generic_unproductive_cycle_is_reported() {
+    let program_text = "
+        trait A { }
+        struct S<T> { }
+        impl<T> A for S<T> where S<T>: A { }
+    ";
+    let error = cycle_error(program_text, "forall<T> { S<T>: A }").unwrap();
+    assert_eq!(error.clauses.len(), 1);
+}
+
+#[test]
+fn coinductive_cycle_is_productive() {
+    let program_text = "
+        #[coinductive]
+        trait C { }
+        struct X { }
+        impl C for X where X: C { }
+    ";
+    assert_eq!(cycle_error(program_text, "X: C"), None);
+}
+
+#[test]
+fn cycle_with_new_bindings_is_productive() {
+    let program_text = "
+        trait A { }
+        struct S<T> { }
+        impl<T> A for S<T> where T: A { }
+    ";
+    assert_eq!(cycle_error(program_text, "exists<T> { T: A }"), None);
+}
+
+#[test]
+fn cycle_with_a_way_out_is_productive() {
+    let program_text = "
+        trait A { }
+        trait B { }
+        struct X { }
+        impl A for X where X: B { }
+        impl B for X where X: A { }
+    ";
+    assert_eq!(cycle_error(program_text, "if (X: B) { X: A }"), None);
+}
+
+#[test]
+fn missing_impls_are_not_cycles() {
+    let program_text = "
+        trait A { }
+        trait B { }
+        struct X { }
+        impl A for X where X: B { }
+    ";
+    assert_eq!(cycle_error(program_text, "X: A"), None);
+}
+
+/// A program where `X: T0` can be proven through either `X: A0` or `X: B0`,
+/// both of which need `X: T1`, and so on down to `X: T{layers}`, which has
+/// the impls in `bottom`. Without memoization the checker would walk the
+/// `2^layers` paths down to the bottom.
+fn diamond_program(layers: usize, bottom: &str) -> String {
+    let mut program_text = String::from("struct X { }\n");
+    for layer in 0..layers {
+        let next = layer + 1;
+        program_text += &format!(
+            "
+            #[marker] trait T{layer} {{ }}
+            trait A{layer} {{ }}
+            trait B{layer} {{ }}
+            impl T{layer} for X where X: A{layer} {{ }}
+            impl T{layer} for X where X: B{layer} {{ }}
+            impl A{layer} for X where X: T{next} {{ }}
+            impl B{layer} for X where X: T{next} {{ }}
+            "
+        );
+    }
+    program_text += &format!("trait T{} {{ }}\n{}", layers, bottom);
+    program_text
+}
+
+#[test]
+fn diamond_without_cycles_is_checked_once_per_goal() {
+    let program_text = diamond_program(40, "");
+    assert_eq!(cycle_error(&program_text, "X: T0"), None);
+}
+
+#[test]
+fn cycle_below_a_diamond_is_checked_once_per_goal() {
+    let program_text = diamond_program(40, "impl T40 for X where X: T0 { }");
+    let error = cycle_error(&program_text, "X: T0").unwrap();
+    assert_eq!(error.clauses.len(), 81);
+}
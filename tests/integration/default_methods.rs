@@ -0,0 +1,90 @@
+use super::util::trait_id;
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::query::LoweringDatabase;
+use chalk_integration::SolverChoice;
+use chalk_ir::cast::Cast;
+use chalk_ir::*;
+use chalk_solve::ext::GoalExt;
+use chalk_solve::RustIrDatabase;
+
+/// Checks whether `Self: trait_name` holds inside the body of the default
+/// method `method` of trait `Foo`, which has no parameters besides `Self`.
+fn self_implements_in_body(db: &ChalkDatabase, method: &str, trait_name: &str) -> bool {
+    let interner = ChalkIr;
+    let program = db.program_ir().unwrap();
+    let foo_trait = trait_id(&program, "Foo");
+    let datum = db
+        .default_methods(foo_trait)
+        .into_iter()
+        .find(|datum| datum.name.to_string() == method)
+        .unwrap();
+
+    let self_ty = TyKind::BoundVar(BoundVar::new(DebruijnIndex::INNERMOST, 0)).intern(interner);
+    let goal: Goal<ChalkIr> = TraitRef {
+        trait_id: trait_id(&program, trait_name),
+        substitution: Substitution::from1(interner, self_ty),
+    }
+    .cast(interner);
+
+    let goal = datum
+        .goal_in_body(interner, goal)
+        .into_closed_goal(interner);
+    db.solve(&goal).is_some_and(|solution| solution.is_unique())
+}
+
+#[test]
+fn default_method_where_clauses_are_assumed_in_body() {
+    let db = ChalkDatabase::with(
+        "
+        trait Clone { }
+        trait Eq { }
+        trait Foo where Self: Eq {
+            fn bar() where Self: Clone { }
+            fn baz() { }
+        }
+        ",
+        SolverChoice::default(),
+    );
+
+    assert!(self_implements_in_body(&db, "bar", "Clone"));
+    assert!(!self_implements_in_body(&db, "baz", "Clone"));
+}
+
+#[test]
+fn required_methods_are_not_default_methods() {
+    let db = ChalkDatabase::with(
+        "
+        trait Clone { }
+        trait Foo {
+            fn bar() where Self: Clone;
+            fn baz() { }
+        }
+        ",
+        SolverChoice::default(),
+    );
+
+    let program = db.program_ir().unwrap();
+    let foo_trait = trait_id(&program, "Foo");
+    let names: Vec<_> = db
+        .default_methods(foo_trait)
+        .iter()
+        .map(|datum| datum.name.to_string())
+        .collect();
+    assert_eq!(names, ["baz"]);
+}
+
+#[test]
+fn default_method_body_assumes_trait_where_clauses() {
+    let db = ChalkDatabase::with(
+        "
+        trait Eq { }
+        trait Foo where Self: Eq {
+            fn baz() { }
+        }
+        ",
+        SolverChoice::default(),
+    );
+
+    assert!(self_implements_in_body(&db, "baz", "Eq"));
+}
@@ -0,0 +1,84 @@
+use super::util::trait_id;
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::program::Program;
+use chalk_integration::query::LoweringDatabase;
+use chalk_integration::{Identifier, SolverChoice};
+use chalk_ir::*;
+use chalk_solve::any_impl_could_match;
+
+fn adt_ty(program: &Program, name: &str, parameters: &[Ty<ChalkIr>]) -> Ty<ChalkIr> {
+    let adt_id = program.adt_ids[&Identifier::from(name)];
+    TyKind::Adt(
+        adt_id,
+        Substitution::from_iter(ChalkIr, parameters.iter().cloned()),
+    )
+    .intern(ChalkIr)
+}
+
+fn u32_ty() -> Ty<ChalkIr> {
+    TyKind::Scalar(Scalar::Uint(UintTy::U32)).intern(ChalkIr)
+}
+
+#[test]
+fn generic_impl_could_match() {
+    let db = ChalkDatabase::with(
+        "
+        trait Foo { }
+        struct Vec<T> { }
+        struct Bar { }
+        impl<T> Foo for Vec<T> where T: Foo { }
+        ",
+        SolverChoice::default(),
+    );
+    let program = db.program_ir().unwrap();
+    let foo = trait_id(&program, "Foo");
+
+    // The where clause `T: Foo` is not checked.
+    let vec_u32 = adt_ty(&program, "Vec", &[u32_ty()]);
+    assert!(any_impl_could_match(foo, &vec_u32, &*program));
+
+    let bar = adt_ty(&program, "Bar", &[]);
+    assert!(!any_impl_could_match(foo, &bar, &*program));
+}
+
+#[test]
+fn impl_could_match_ignores_other_trait_parameters() {
+    let db = ChalkDatabase::with(
+        "
+        trait Into<T> { }
+        struct A { }
+        struct B { }
+        impl Into<B> for A { }
+        ",
+        SolverChoice::default(),
+    );
+    let program = db.program_ir().unwrap();
+    let into = trait_id(&program, "Into");
+
+    let a = adt_ty(&program, "A", &[]);
+    let b = adt_ty(&program, "B", &[]);
+    assert!(any_impl_could_match(into, &a, &*program));
+    assert!(!any_impl_could_match(into, &b, &*program));
+}
+
+#[test]
+fn auto_trait_impl_could_match_structs() {
+    let db = ChalkDatabase::with(
+        "
+        #[auto] trait Send { }
+        struct Foo { }
+        struct Bar { }
+        impl !Send for Bar { }
+        ",
+        SolverChoice::default(),
+    );
+    let program = db.program_ir().unwrap();
+    let send = trait_id(&program, "Send");
+
+    let foo = adt_ty(&program, "Foo", &[]);
+    assert!(any_impl_could_match(send, &foo, &*program));
+
+    let bar = adt_ty(&program, "Bar", &[]);
+    assert!(!any_impl_could_match(send, &bar, &*program));
+}
@@ -1 +1,20 @@
+mod answers;
+mod clause_dump;
+mod cycles;
+mod default_methods;
+mod impl_could_match;
+mod numeric_fallback;
 mod panic;
+mod program_clauses;
+mod reachable;
+mod recursive_cache;
+mod simplify_goal;
+mod slg_cache;
+mod solve_all;
+mod solve_limited;
+mod solve_with_assumptions;
+#[cfg(feature = "specialization")]
+mod specialization;
+mod trait_ref;
+mod util;
+mod wf;
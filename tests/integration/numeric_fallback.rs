@@ -0,0 +1,63 @@
+use super::util::db_and_goal;
+use chalk_integration::SolverChoice;
+use chalk_solve::RustIrDatabase;
+
+const PROGRAM: &str = "
+    trait Add { }
+    trait Shl { }
+    trait Index<T> { }
+    struct Slice { }
+
+    impl Add for i32 { }
+    impl Add for u32 { }
+    impl Add for f32 { }
+    impl Add for f64 { }
+    impl Shl for u32 { }
+    impl Shl for u64 { }
+    impl Index<usize> for Slice { }
+";
+
+fn solve(goal_text: &str, fallback: bool) -> String {
+    let (db, goal) = db_and_goal(PROGRAM, SolverChoice::default(), goal_text);
+    let mut solver = SolverChoice::default().into_solver();
+    let solution = if fallback {
+        solver.solve_with_numeric_fallback(&db, &goal)
+    } else {
+        solver.solve(&db, &goal)
+    };
+    db.with_program(|_| match solution {
+        Some(solution) => solution.display(db.interner()).to_string(),
+        None => "No possible solution".to_string(),
+    })
+}
+
+#[test]
+fn unconstrained_integer_defaults_to_i32() {
+    let goal = "exists<int N> { N: Add }";
+    assert_eq!(solve(goal, false), "Ambiguous; no inference guidance");
+    assert_eq!(solve(goal, true), "Unique; substitution [?0 := Int(I32)]");
+    assert_eq!(solve("i32: Add", true), "Unique");
+}
+
+#[test]
+fn unconstrained_float_defaults_to_f64() {
+    assert_eq!(
+        solve("exists<float N> { N: Add }", true),
+        "Unique; substitution [?0 := Float(F64)]"
+    );
+}
+
+#[test]
+fn numeric_fallback_does_not_override_other_constraints() {
+    // `Index` pins the variable to `usize`, so the default never applies.
+    assert_eq!(
+        solve("exists<int N> { Slice: Index<N> }", true),
+        "Unique; substitution [?0 := Uint(Usize)]"
+    );
+
+    // `i32: Shl` does not hold, so the ambiguity is preserved.
+    assert_eq!(
+        solve("exists<int N> { N: Shl }", true),
+        "Ambiguous; no inference guidance"
+    );
+}
@@ -68,20 +68,14 @@ impl RustIrDatabase<ChalkIr> for MockDatabase {
         Arc::new(TraitDatum {
             id,
             binders: Binders::new(
-                VariableKinds::empty(ChalkIr),
+                VariableKinds::from1(ChalkIr, VariableKind::Ty(TyVariableKind::General)),
                 TraitDatumBound {
                     where_clauses: vec![],
                 },
             ),
-            flags: TraitFlags {
-                auto: false,
-                marker: false,
-                upstream: false,
-                fundamental: false,
-                non_enumerable: false,
-                coinductive: false,
-            },
+            flags: TraitFlags::default(),
             associated_ty_ids: vec![],
+            associated_const_ids: vec![],
             well_known: None,
         })
     }
@@ -115,6 +109,7 @@ impl RustIrDatabase<ChalkIr> for MockDatabase {
             binders,
             impl_type: ImplType::Local,
             associated_ty_value_ids: vec![],
+            associated_const_values: vec![],
         })
     }
 
@@ -0,0 +1,434 @@
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::query::LoweringDatabase;
+use chalk_integration::Identifier;
+use chalk_integration::SolverChoice;
+use chalk_ir::cast::Cast;
+use chalk_ir::*;
+use chalk_solve::clauses::program_clauses_that_could_match;
+use chalk_solve::clauses::validate::{
+    validate_bound_vars, validate_generic_arg_kinds, BoundVarError, KindError, KindMismatchItem,
+};
+use chalk_solve::display::{write_domain_goal, write_program_clause, WriterState};
+use chalk_solve::ext::GoalExt;
+use chalk_solve::RustIrDatabase;
+use std::collections::HashSet;
+
+#[test]
+fn program_clauses_for_goal_are_deduplicated() {
+    let db = ChalkDatabase::with("trait Foo { }", SolverChoice::default());
+
+    // The rules for `Foo` come both from the goal itself and from
+    // elaborating the `T: Foo` in the environment.
+    let goal = db
+        .parse_and_lower_goal("forall<T> { if (T: Foo) { T: Foo } }")
+        .unwrap()
+        .into_peeled_goal(db.interner());
+
+    let clauses = db.program_clauses_for_goal(&goal).unwrap();
+    assert!(!clauses.is_empty());
+    let unique: HashSet<_> = clauses.iter().collect();
+    assert_eq!(
+        unique.len(),
+        clauses.len(),
+        "duplicate clauses: {:#?}",
+        clauses
+    );
+}
+
+#[test]
+fn program_clauses_for_goal_order() {
+    let db = ChalkDatabase::with(
+        "trait Foo { } struct Bar { } struct Baz { } impl Foo for Bar { } impl Foo for Baz { }",
+        SolverChoice::default(),
+    );
+    let goal = db
+        .parse_and_lower_goal("exists<T> { T: Foo }")
+        .unwrap()
+        .into_peeled_goal(db.interner());
+
+    let clauses = db.with_program(|_| {
+        db.program_clauses_for_goal(&goal)
+            .unwrap()
+            .iter()
+            .map(|clause| format!("{:?}", clause))
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(
+        clauses,
+        [
+            "for<type> Implemented(^0.0: Foo) :- FromEnv(^0.0: Foo)",
+            "for<> Implemented(Bar: Foo)",
+            "for<> Implemented(Baz: Foo)",
+        ]
+    );
+}
+
+#[test]
+fn program_clauses_for_non_domain_goal() {
+    let db = ChalkDatabase::with("trait Foo { }", SolverChoice::default());
+    let goal = db
+        .parse_and_lower_goal("not { u32: Foo }")
+        .unwrap()
+        .into_peeled_goal(db.interner());
+
+    let error = db.program_clauses_for_goal(&goal).unwrap_err();
+    assert_eq!(error.to_string(), "not a domain goal");
+}
+
+#[test]
+fn program_clauses_have_valid_bound_vars() {
+    let db = ChalkDatabase::with(
+        "trait Foo<T> { } struct Bar<T> { } impl<T, U> Foo<T> for Bar<U> where U: Foo<T> { }",
+        SolverChoice::default(),
+    );
+    let goal = db
+        .parse_and_lower_goal("forall<T> { exists<U> { Bar<U>: Foo<T> } }")
+        .unwrap()
+        .into_peeled_goal(db.interner());
+
+    for clause in db.program_clauses_for_goal(&goal).unwrap() {
+        assert_eq!(validate_bound_vars(ChalkIr, &clause), Ok(()));
+    }
+}
+
+#[test]
+fn malformed_bound_vars_are_flagged() {
+    let interner = ChalkIr;
+    // `for<type> WellFormed(^debruijn.index)`
+    let clause = |debruijn, index| {
+        let ty =
+            TyKind::BoundVar(BoundVar::new(DebruijnIndex::new(debruijn), index)).intern(interner);
+        ProgramClauseData(Binders::new(
+            VariableKinds::from1(interner, VariableKind::Ty(TyVariableKind::General)),
+            ProgramClauseImplication {
+                consequence: DomainGoal::WellFormed(WellFormed::Ty(ty)),
+                conditions: Goals::empty(interner),
+                constraints: Constraints::empty(interner),
+                priority: ClausePriority::High,
+            },
+        ))
+        .intern(interner)
+    };
+
+    assert_eq!(validate_bound_vars(interner, &clause(0, 0)), Ok(()));
+    assert_eq!(
+        validate_bound_vars(interner, &clause(0, 1)),
+        Err(BoundVarError::IndexOutOfRange {
+            bound_var: BoundVar::new(DebruijnIndex::INNERMOST, 1),
+            len: 1,
+        })
+    );
+    assert_eq!(
+        validate_bound_vars(interner, &clause(1, 0)),
+        Err(BoundVarError::Escaping(BoundVar::new(
+            DebruijnIndex::ONE,
+            0
+        )))
+    );
+}
+
+#[test]
+fn program_clauses_have_valid_generic_arg_kinds() {
+    let db = ChalkDatabase::with(
+        "
+        trait Foo<'a, T> { type Assoc<U>; }
+        struct Bar<'a, T> { }
+        impl<'a, T> Foo<'a, T> for Bar<'a, T> { type Assoc<U> = U; }
+        ",
+        SolverChoice::default(),
+    );
+    let goal = db
+        .parse_and_lower_goal(
+            "forall<'a, T> { exists<U, V> { Normalize(<Bar<'a, T> as Foo<'a, T>>::Assoc<U> -> V) } }",
+        )
+        .unwrap()
+        .into_peeled_goal(db.interner());
+
+    let clauses = db.program_clauses_for_goal(&goal).unwrap();
+    assert!(!clauses.is_empty());
+    for clause in clauses {
+        assert_eq!(validate_generic_arg_kinds(&db, &clause), Ok(()));
+    }
+}
+
+#[test]
+fn kind_mismatched_substitutions_are_flagged() {
+    let interner = ChalkIr;
+    let db = ChalkDatabase::with(
+        "trait Foo<T> { } struct Bar<T> { }",
+        SolverChoice::default(),
+    );
+    let program = db.program_ir().unwrap();
+    let foo = program.trait_ids[&Identifier::from("Foo")];
+    let bar = program.adt_ids[&Identifier::from("Bar")];
+
+    let bound_lifetime =
+        LifetimeData::BoundVar(BoundVar::new(DebruijnIndex::INNERMOST, 0)).intern(interner);
+    let u32_ty = TyKind::Scalar(Scalar::Uint(UintTy::U32)).intern(interner);
+    // `for<lifetime> FromEnv(self_ty: Foo<trait_args..>)`
+    let clause = |self_ty: Ty<ChalkIr>, trait_args: &[GenericArg<ChalkIr>]| {
+        let trait_ref = TraitRef {
+            trait_id: foo,
+            substitution: Substitution::from_iter(
+                interner,
+                Some(self_ty.cast(interner))
+                    .into_iter()
+                    .chain(trait_args.iter().cloned()),
+            ),
+        };
+        ProgramClauseData(Binders::new(
+            VariableKinds::from1(interner, VariableKind::Lifetime),
+            ProgramClauseImplication {
+                consequence: DomainGoal::FromEnv(FromEnv::Trait(trait_ref)),
+                conditions: Goals::empty(interner),
+                constraints: Constraints::empty(interner),
+                priority: ClausePriority::High,
+            },
+        ))
+        .intern(interner)
+    };
+    let bar_of = |arg: GenericArg<ChalkIr>| {
+        TyKind::Adt(bar, Substitution::from1(interner, arg)).intern(interner)
+    };
+
+    let well_kinded = clause(
+        bar_of(u32_ty.clone().cast(interner)),
+        &[u32_ty.clone().cast(interner)],
+    );
+    assert_eq!(validate_generic_arg_kinds(&*program, &well_kinded), Ok(()));
+
+    // A lifetime is passed where a type parameter is declared.
+    let lifetime_for_type = clause(
+        u32_ty.clone(),
+        &[bar_of(bound_lifetime.cast(interner)).cast(interner)],
+    );
+    assert_eq!(
+        validate_generic_arg_kinds(&*program, &lifetime_for_type),
+        Err(KindError::Mismatch {
+            item: KindMismatchItem::Adt(bar),
+            index: 0,
+        })
+    );
+    let lifetime_for_type = clause(u32_ty.clone(), &[bound_lifetime.cast(interner)]);
+    assert_eq!(
+        validate_generic_arg_kinds(&*program, &lifetime_for_type),
+        Err(KindError::Mismatch {
+            item: KindMismatchItem::Trait(foo),
+            index: 1,
+        })
+    );
+
+    let missing_arg = clause(u32_ty, &[]);
+    assert_eq!(
+        validate_generic_arg_kinds(&*program, &missing_arg),
+        Err(KindError::WrongArgCount {
+            item: KindMismatchItem::Trait(foo),
+            expected: 2,
+            actual: 1,
+        })
+    );
+}
+
+fn rendered_clauses(program_text: &str, goal_text: &str) -> Vec<String> {
+    let db = ChalkDatabase::with(program_text, SolverChoice::default());
+    let goal = db
+        .parse_and_lower_goal(goal_text)
+        .unwrap()
+        .into_peeled_goal(db.interner());
+    let ws = WriterState::<_, ChalkDatabase, _>::new(&db);
+    db.with_program(|_| {
+        db.program_clauses_for_goal(&goal)
+            .unwrap()
+            .iter()
+            .map(|clause| {
+                let mut out = String::new();
+                write_program_clause(&mut out, &ws, clause).unwrap();
+                out
+            })
+            .collect()
+    })
+}
+
+#[test]
+fn auto_trait_clause_renders_in_documented_form() {
+    // The example from the docs of `push_auto_trait_impls`.
+    let clauses = rendered_clauses(
+        "
+        #[auto] trait Send { }
+        struct Box<T> { }
+        struct Option<T> { }
+        struct MyList<T> {
+            data: T,
+            next: Box<Option<MyList<T>>>
+        }
+        ",
+        "exists<T> { MyList<T>: Send }",
+    );
+    assert_eq!(
+        clauses,
+        [
+            "forall<_1_0> {\n  Implemented(_1_0: Send) :-\n    FromEnv(_1_0: Send).\n}",
+            "forall<_1_0> {
+  Implemented(MyList<_1_0>: Send) :-
+    Implemented(_1_0: Send),
+    Implemented(Box<Option<MyList<_1_0>>>: Send).
+}",
+        ]
+    );
+}
+
+#[test]
+fn impl_clauses_render_in_surface_syntax() {
+    let clauses = rendered_clauses(
+        "
+        trait Clone { }
+        trait Iterator { type Item; }
+        struct Foo<T> { }
+        struct Bar { }
+        impl<T> Iterator for Foo<T> where T: Clone { type Item = T; }
+        impl Clone for Bar { }
+        ",
+        "exists<T, U> { Normalize(<Foo<T> as Iterator>::Item -> U) }",
+    );
+    assert_eq!(
+        clauses,
+        ["forall<_1_0> {
+  Normalize(<Foo<_1_0> as Iterator>::Item -> _1_0) :-
+    Implemented(_1_0: Clone).
+}"]
+    );
+
+    let clauses = rendered_clauses(
+        "trait Clone { } struct Bar { } impl Clone for Bar { }",
+        "Bar: Clone",
+    );
+    assert!(clauses.contains(&"Implemented(Bar: Clone).".to_owned()));
+}
+
+#[test]
+fn domain_goals_render_in_surface_syntax() {
+    let db = ChalkDatabase::with(
+        "trait Clone { } struct Bar { } impl Clone for Bar { }",
+        SolverChoice::default(),
+    );
+    let goal = db
+        .parse_and_lower_goal("Bar: Clone")
+        .unwrap()
+        .into_peeled_goal(db.interner());
+    let ws = WriterState::<_, ChalkDatabase, _>::new(&db);
+    let domain_goal = match goal.canonical.value.goal.data(db.interner()) {
+        GoalData::DomainGoal(domain_goal) => domain_goal.clone(),
+        _ => panic!("expected a domain goal"),
+    };
+    let mut out = String::new();
+    db.with_program(|_| write_domain_goal(&mut out, &ws, &domain_goal).unwrap());
+    assert_eq!(out, "Implemented(Bar: Clone)");
+}
+
+#[test]
+fn program_clauses_flounder_on_inference_var_self_type() {
+    let db = ChalkDatabase::with("trait Foo { }", SolverChoice::default());
+    let interner = ChalkIr;
+    let ty = TyKind::InferenceVar(InferenceVar::from(0), TyVariableKind::General).intern(interner);
+    let goal = UCanonical {
+        canonical: Canonical {
+            value: InEnvironment::new(
+                &Environment::new(interner),
+                DomainGoal::WellFormed(WellFormed::Ty(ty)),
+            ),
+            binders: CanonicalVarKinds::empty(interner),
+        },
+        universes: 1,
+    };
+
+    let result = program_clauses_that_could_match(&db, &goal);
+    assert!(matches!(result, Err(Floundered)));
+}
+
+#[test]
+fn merged_environment_elaborates_like_piecewise_one() {
+    let db = ChalkDatabase::with(
+        "
+        trait A { }
+        trait B where Self: A { }
+        trait C where Self: B { }
+        trait D where Self: A { }
+        ",
+        SolverChoice::default(),
+    );
+    let interner = db.interner();
+    let environment = |goal_text| {
+        db.parse_and_lower_goal(goal_text)
+            .unwrap()
+            .into_peeled_goal(interner)
+            .canonical
+            .value
+            .environment
+    };
+    let first = environment("forall<T> { if (T: C; T: B) { T: A } }");
+    let second = environment("forall<T> { if (T: B; T: D) { T: A } }");
+
+    // `T: B` is only kept once.
+    let merged = first.merge(interner, &second);
+    assert_eq!(merged.clauses.len(interner), 3);
+
+    let piecewise = first
+        .clauses
+        .iter(interner)
+        .chain(second.clauses.iter(interner))
+        .fold(Environment::new(interner), |env, clause| {
+            env.add_clauses(interner, Some(clause.clone()))
+        });
+    assert_eq!(
+        db.program_clauses_for_env(&merged),
+        db.program_clauses_for_env(&piecewise)
+    );
+}
+
+#[test]
+fn inference_var_self_type_matches_like_unbound_var() {
+    let interner = ChalkIr;
+    let db = ChalkDatabase::with(
+        "trait Foo { } struct Bar { } impl Foo for Bar { }",
+        SolverChoice::default(),
+    );
+    let trait_id = db.program_ir().unwrap().trait_ids[&Identifier::from("Foo")];
+    let goal = |ty: Ty<ChalkIr>, binders| UCanonical {
+        canonical: Canonical {
+            value: InEnvironment::new(
+                &Environment::new(interner),
+                DomainGoal::Holds(WhereClause::Implemented(TraitRef {
+                    trait_id,
+                    substitution: Substitution::from1(interner, ty),
+                })),
+            ),
+            binders,
+        },
+        universes: 1,
+    };
+
+    // `Implemented(?0: Foo)` used to panic instead of being treated like
+    // `exists<T> { Implemented(T: Foo) }`.
+    let inference_var =
+        TyKind::InferenceVar(InferenceVar::from(0), TyVariableKind::General).intern(interner);
+    let bound_var = TyKind::BoundVar(BoundVar::new(DebruijnIndex::INNERMOST, 0)).intern(interner);
+    let bound_var_binders = CanonicalVarKinds::from1(
+        interner,
+        CanonicalVarKind::new(
+            VariableKind::Ty(TyVariableKind::General),
+            UniverseIndex::ROOT,
+        ),
+    );
+    let clauses = program_clauses_that_could_match(
+        &db,
+        &goal(inference_var, CanonicalVarKinds::empty(interner)),
+    )
+    .ok()
+    .unwrap();
+    let expected = program_clauses_that_could_match(&db, &goal(bound_var, bound_var_binders))
+        .ok()
+        .unwrap();
+    assert_eq!(clauses, expected);
+    assert!(clauses.len() > 1);
+}
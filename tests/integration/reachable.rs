@@ -0,0 +1,102 @@
+use super::util::{closed_goal, trait_id};
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::program::Program;
+use chalk_integration::query::LoweringDatabase;
+use chalk_integration::{Identifier, SolverChoice};
+use chalk_ir::*;
+use chalk_solve::clauses::reachable::unreachable_impls;
+
+/// The impl of `trait_name` whose self type is the struct `self_ty`.
+fn impl_for(program: &Program, trait_name: &str, self_ty: &str) -> ImplId<ChalkIr> {
+    let trait_id = trait_id(program, trait_name);
+    let adt_id = program.adt_ids[&Identifier::from(self_ty)];
+    *program
+        .impl_data
+        .iter()
+        .find(|(_, datum)| {
+            let trait_ref = &datum.binders.skip_binders().trait_ref;
+            trait_ref.trait_id == trait_id
+                && matches!(
+                    trait_ref.self_type_parameter(ChalkIr).kind(ChalkIr),
+                    TyKind::Adt(id, _) if *id == adt_id
+                )
+        })
+        .unwrap()
+        .0
+}
+
+#[test]
+fn impls_unreachable_from_roots_are_reported() {
+    let db = ChalkDatabase::with(
+        "
+        trait Foo { }
+        trait Bar { }
+        struct A { }
+        struct B { }
+        struct Wrapper<T> { }
+        impl<T> Foo for Wrapper<T> where T: Bar { }
+        impl Foo for B { }
+        impl Bar for A { }
+        impl Bar for B { }
+        ",
+        SolverChoice::default(),
+    );
+    let program = db.program_ir().unwrap();
+    let root = closed_goal(&db, "Wrapper<A>: Foo");
+    let roots = [root];
+
+    let foo = trait_id(&program, "Foo");
+    assert_eq!(
+        unreachable_impls(&*program, &roots, foo),
+        [impl_for(&program, "Foo", "B")]
+    );
+
+    // `A: Bar` is only reachable through the conditions of the `Wrapper` impl.
+    let bar = trait_id(&program, "Bar");
+    assert_eq!(
+        unreachable_impls(&*program, &roots, bar),
+        [impl_for(&program, "Bar", "B")]
+    );
+}
+
+#[test]
+fn generic_roots_reach_every_matching_impl() {
+    let db = ChalkDatabase::with(
+        "
+        trait Foo { }
+        struct A { }
+        struct B { }
+        impl Foo for A { }
+        impl Foo for B { }
+        ",
+        SolverChoice::default(),
+    );
+    let program = db.program_ir().unwrap();
+    let root = closed_goal(&db, "exists<T> { T: Foo }");
+
+    let foo = trait_id(&program, "Foo");
+    assert!(unreachable_impls(&*program, &[root], foo).is_empty());
+}
+
+#[test]
+fn recursive_impl_walk_terminates() {
+    let db = ChalkDatabase::with(
+        "
+        trait Foo { }
+        struct Box<T> { }
+        struct A { }
+        impl<T> Foo for Box<T> where Box<Box<T>>: Foo { }
+        impl Foo for A { }
+        ",
+        SolverChoice::default(),
+    );
+    let program = db.program_ir().unwrap();
+    let root = closed_goal(&db, "Box<u32>: Foo");
+
+    let foo = trait_id(&program, "Foo");
+    assert_eq!(
+        unreachable_impls(&*program, &[root], foo),
+        [impl_for(&program, "Foo", "A")]
+    );
+}
@@ -0,0 +1,43 @@
+use super::util::peeled_goal;
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::SolverChoice;
+use chalk_solve::Solution;
+
+#[test]
+fn overflow_is_not_cached() {
+    // The program is checked with the default solver, since with a limit of
+    // six frames the impls for the later traits can't be proven well-formed.
+    let db = ChalkDatabase::with(
+        "
+        trait T0 { }
+        trait T1 { }
+        trait T2 { }
+        trait T3 { }
+        trait T4 { }
+        trait T5 { }
+        trait T6 { }
+        trait T7 { }
+        struct Z { }
+
+        impl T0 for Z { }
+        impl<X> T1 for X where X: T0 { }
+        impl<X> T2 for X where X: T1 { }
+        impl<X> T3 for X where X: T2 { }
+        impl<X> T4 for X where X: T3 { }
+        impl<X> T5 for X where X: T4 { }
+        impl<X> T6 for X where X: T5 { }
+        impl<X> T7 for X where X: T6 { }
+        ",
+        SolverChoice::default(),
+    );
+    let goal = |text| peeled_goal(&db, text);
+
+    // Proving `Z: T7` overflows on the way down to `Z: T0`, after `Z: T2`
+    // has been given up on as well. That is no reason for the same solver
+    // to give up on `Z: T2` once it is asked directly, within the limit.
+    let mut solver = SolverChoice::recursive(30, 6).into_solver();
+    let solution = solver.solve(&db, &goal("Z: T7"));
+    assert_eq!(solution, Some(Solution::Overflow));
+    let solution = solver.solve(&db, &goal("Z: T2"));
+    assert!(solution.unwrap().is_unique());
+}
@@ -0,0 +1,163 @@
+use chalk_engine::solve::{SLGSolver, SolutionCacheKey};
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::SolverChoice;
+use chalk_ir::*;
+use chalk_recursive::RecursiveSolver;
+use chalk_solve::ext::GoalExt;
+use chalk_solve::solve::Cache;
+use chalk_solve::Solver;
+
+const PROGRAM: &str = "
+    trait Foo { }
+    trait Bar { }
+    struct A { }
+    struct B { }
+    impl Foo for A { }
+    impl Bar for A { }
+    impl<T> Foo for T where T: Bar { }
+";
+
+struct Harness {
+    db: ChalkDatabase,
+}
+
+impl Harness {
+    fn new() -> Self {
+        Harness {
+            db: ChalkDatabase::with(PROGRAM, SolverChoice::default()),
+        }
+    }
+
+    fn goal(&self, text: &str) -> Goal<ChalkIr> {
+        self.db.parse_and_lower_goal(text).unwrap()
+    }
+
+    /// Checks that `goal` simplifies to `expected` and that both are solved
+    /// the same way by each solver.
+    fn assert_simplifies_to(&self, goal: Goal<ChalkIr>, expected: Goal<ChalkIr>) {
+        let simplified = goal.simplify(ChalkIr);
+        assert_eq!(simplified, expected);
+        for solver_choice in [
+            SolverChoice::slg_default(),
+            SolverChoice::recursive_default(),
+        ] {
+            let solve = |goal: &Goal<ChalkIr>| {
+                solver_choice
+                    .into_solver()
+                    .solve(&self.db, &goal.clone().into_closed_goal(ChalkIr))
+            };
+            assert_eq!(solve(&goal), solve(&simplified), "{:?}", goal);
+        }
+    }
+}
+
+fn trivially_true() -> Goal<ChalkIr> {
+    Goal::all(ChalkIr, None)
+}
+
+#[test]
+fn nested_conjunctions_are_flattened() {
+    let h = Harness::new();
+    h.assert_simplifies_to(
+        h.goal("A: Foo, (A: Bar, (B: Foo, A: Foo))"),
+        h.goal("A: Foo, A: Bar, B: Foo, A: Foo"),
+    );
+}
+
+#[test]
+fn trivially_true_conjuncts_are_dropped() {
+    let h = Harness::new();
+    h.assert_simplifies_to(
+        Goal::all(
+            ChalkIr,
+            vec![trivially_true(), h.goal("A: Foo"), trivially_true()],
+        ),
+        h.goal("A: Foo"),
+    );
+    h.assert_simplifies_to(
+        Goal::all(ChalkIr, vec![trivially_true(), trivially_true()]),
+        trivially_true(),
+    );
+}
+
+#[test]
+fn quantifiers_without_variables_are_removed() {
+    let h = Harness::new();
+    h.assert_simplifies_to(h.goal("exists<> { B: Foo }"), h.goal("B: Foo"));
+    h.assert_simplifies_to(
+        h.goal("forall<> { exists<T> { forall<> { T: Foo } } }"),
+        h.goal("exists<T> { T: Foo }"),
+    );
+}
+
+#[test]
+fn quantified_trivially_true_goals_are_removed() {
+    let h = Harness::new();
+    let kinds = VariableKinds::from1(ChalkIr, VariableKind::Ty(TyVariableKind::General));
+    h.assert_simplifies_to(
+        trivially_true().quantify(ChalkIr, QuantifierKind::ForAll, kinds.clone()),
+        trivially_true(),
+    );
+    h.assert_simplifies_to(
+        Goal::all(ChalkIr, vec![trivially_true(), trivially_true()]).quantify(
+            ChalkIr,
+            QuantifierKind::Exists,
+            kinds,
+        ),
+        trivially_true(),
+    );
+}
+
+#[test]
+fn trivial_implications_are_removed() {
+    let h = Harness::new();
+    h.assert_simplifies_to(
+        h.goal("if () { A: Foo, (A: Bar) }"),
+        h.goal("A: Foo, A: Bar"),
+    );
+
+    let clauses = match h.goal("if (B: Bar) { B: Foo }").data(ChalkIr) {
+        GoalData::Implies(clauses, _) => clauses.clone(),
+        goal => panic!("unexpected goal {:?}", goal),
+    };
+    h.assert_simplifies_to(
+        trivially_true().implied_by(ChalkIr, clauses),
+        trivially_true(),
+    );
+}
+
+#[test]
+fn simplification_reaches_under_binders() {
+    let h = Harness::new();
+    h.assert_simplifies_to(
+        h.goal("forall<T> { if (T: Bar) { exists<> { T: Foo, (T: Bar) } } }"),
+        h.goal("forall<T> { if (T: Bar) { T: Foo, T: Bar } }"),
+    );
+    h.assert_simplifies_to(
+        h.goal("not { exists<> { B: Foo, (B: Bar) } }"),
+        h.goal("not { B: Foo, B: Bar }"),
+    );
+}
+
+#[test]
+fn solvers_simplify_goals() {
+    let h = Harness::new();
+    let goal =
+        Goal::all(ChalkIr, vec![trivially_true(), h.goal("A: Foo")]).into_closed_goal(ChalkIr);
+    let simplified = h.goal("A: Foo").into_closed_goal(ChalkIr);
+
+    // Each solver caches the solution under the simplified goal.
+    let cache = Cache::new();
+    SLGSolver::new(10, None, Some(cache.clone())).solve(&h.db, &goal);
+    let key = SolutionCacheKey {
+        goal: simplified.clone(),
+        max_size: 10,
+        expected_answers: None,
+    };
+    assert!(cache.get(&key).is_some());
+
+    let cache = Cache::new();
+    RecursiveSolver::new(10, 30, Some(cache.clone())).solve(&h.db, &goal);
+    assert!(cache.get(&simplified).is_some());
+}
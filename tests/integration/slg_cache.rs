@@ -0,0 +1,111 @@
+use super::util::{peeled_goal, UCanonicalGoal};
+use chalk_engine::solve::{SLGSolver, SolutionCacheKey};
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::SolverChoice;
+use chalk_solve::solve::Cache;
+use chalk_solve::{Guidance, Solution, Solver};
+use std::cell::Cell;
+
+/// The key under which an `SLGSolver::new(10, None, ..)` caches `goal`.
+fn key(goal: &UCanonicalGoal) -> SolutionCacheKey<ChalkIr> {
+    SolutionCacheKey {
+        goal: goal.clone(),
+        max_size: 10,
+        expected_answers: None,
+    }
+}
+
+#[test]
+fn second_solve_hits_shared_cache() {
+    let db = ChalkDatabase::with(
+        "
+        trait Clone { }
+        impl Clone for i32 { }
+        ",
+        SolverChoice::slg_default(),
+    );
+    let goal = peeled_goal(&db, "i32: Clone");
+
+    let cache = Cache::new();
+    let solution = SLGSolver::new(10, None, Some(cache.clone())).solve(&db, &goal);
+    assert!(solution.as_ref().unwrap().is_unique());
+    assert_eq!(cache.get(&key(&goal)), Some(solution));
+
+    // A solver that isn't allowed to take a single step gives up on the
+    // goal, unless it shares the cache and can reuse the cached solution.
+    let give_up = || false;
+    let solution = SLGSolver::new(10, None, None).solve_limited(&db, &goal, &give_up);
+    assert_eq!(solution, Some(Solution::Ambig(Guidance::Unknown)));
+    let solution =
+        SLGSolver::new(10, None, Some(cache.clone())).solve_limited(&db, &goal, &give_up);
+    assert!(solution.unwrap().is_unique());
+}
+
+#[test]
+fn solvers_with_other_limits_do_not_share_solutions() {
+    let db = ChalkDatabase::with(
+        "
+        trait Clone { }
+        impl Clone for i32 { }
+        ",
+        SolverChoice::slg_default(),
+    );
+    let goal = peeled_goal(&db, "i32: Clone");
+
+    let cache = Cache::new();
+    SLGSolver::new(10, None, Some(cache.clone())).solve(&db, &goal);
+    let give_up = || false;
+    for mut solver in [
+        SLGSolver::new(20, None, Some(cache.clone())),
+        SLGSolver::new(10, Some(1), Some(cache.clone())),
+    ] {
+        let solution = solver.solve_limited(&db, &goal, &give_up);
+        assert_eq!(solution, Some(Solution::Ambig(Guidance::Unknown)));
+    }
+}
+
+#[test]
+fn goals_with_assumptions_are_not_cached() {
+    let db = ChalkDatabase::with("trait Clone { }", SolverChoice::slg_default());
+    let goal = peeled_goal(&db, "forall<T> { if (T: Clone) { T: Clone } }");
+
+    let cache = Cache::new();
+    let solution = SLGSolver::new(10, None, Some(cache.clone())).solve(&db, &goal);
+    assert!(solution.unwrap().is_unique());
+    assert_eq!(cache.get(&key(&goal)), None);
+}
+
+#[test]
+fn repeated_normalization_skips_the_solver() {
+    let db = ChalkDatabase::with(
+        "
+        trait Iterator { type Item; }
+        trait Clone { }
+        struct Foo<T> { }
+        impl<T> Iterator for Foo<T> where T: Clone { type Item = T; }
+        impl Clone for u32 { }
+        ",
+        SolverChoice::slg_default(),
+    );
+    let goal = peeled_goal(
+        &db,
+        "exists<U> { Normalize(<Foo<u32> as Iterator>::Item -> U) }",
+    );
+
+    // Counts the steps the forest takes to solve `goal` again.
+    let steps = |cache: Option<Cache<_, _>>| {
+        let steps = Cell::new(0);
+        let solution = SLGSolver::new(10, None, cache).solve_limited(&db, &goal, &|| {
+            steps.set(steps.get() + 1);
+            true
+        });
+        assert!(solution.unwrap().is_unique());
+        steps.get()
+    };
+
+    let cache = Cache::new();
+    SLGSolver::new(10, None, Some(cache.clone())).solve(&db, &goal);
+    assert!(steps(None) > 0);
+    assert_eq!(steps(Some(cache)), 0);
+}
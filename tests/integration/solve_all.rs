@@ -0,0 +1,48 @@
+use super::util::peeled_goal;
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::SolverChoice;
+
+const PROGRAM: &str = "
+    trait Clone { }
+    trait Foo { }
+    struct Z { }
+    struct S<T> { }
+    struct Unit { }
+    impl Clone for Z { }
+    impl<T> Clone for S<T> where T: Clone { }
+    impl Foo for Z { }
+    impl Foo for Unit { }
+";
+
+const GOALS: &[&str] = &[
+    "S<S<Z>>: Clone",
+    "Unit: Clone",
+    "exists<T> { T: Foo }",
+    "forall<T> { if (T: Clone) { S<T>: Clone } }",
+    "S<Z>: Clone",
+    "exists<T> { S<T>: Clone }",
+];
+
+#[test]
+fn solve_all_matches_solving_individually() {
+    for solver_choice in [
+        SolverChoice::slg_default(),
+        SolverChoice::recursive_default(),
+    ] {
+        let db = ChalkDatabase::with(PROGRAM, solver_choice);
+        let goals: Vec<_> = GOALS
+            .iter()
+            .map(|goal_text| peeled_goal(&db, goal_text))
+            .collect();
+
+        let batched = solver_choice.into_solver().solve_all(&db, &goals);
+        let individual: Vec<_> = goals
+            .iter()
+            .map(|goal| solver_choice.into_solver().solve(&db, goal))
+            .collect();
+        assert_eq!(batched, individual, "using solver: {:?}", solver_choice);
+        assert!(batched[0].as_ref().unwrap().is_unique());
+        assert_eq!(batched[1], None);
+        assert!(batched[2].as_ref().unwrap().is_ambig());
+    }
+}
@@ -0,0 +1,56 @@
+use super::util::db_and_goal;
+use chalk_integration::SolverChoice;
+use chalk_solve::{Guidance, Solution};
+use std::cell::Cell;
+
+const PROGRAM: &str = "
+    trait Foo { }
+    struct Z { }
+    struct S<T> { }
+    impl Foo for Z { }
+    impl<T> Foo for S<T> where T: Foo { }
+";
+
+#[test]
+fn solve_limited_stops_when_asked() {
+    let (db, goal) = db_and_goal(PROGRAM, SolverChoice::slg_default(), "S<S<S<Z>>>: Foo");
+
+    // Refusing to continue gives up without an answer...
+    let calls = Cell::new(0);
+    let solution = SolverChoice::slg_default()
+        .into_solver()
+        .solve_limited(&db, &goal, &|| {
+            calls.set(calls.get() + 1);
+            false
+        });
+    assert_eq!(solution, Some(Solution::Ambig(Guidance::Unknown)));
+    assert_eq!(calls.get(), 1);
+
+    // ...while allowing the solver to continue finds the solution.
+    let solution = SolverChoice::slg_default()
+        .into_solver()
+        .solve_limited(&db, &goal, &|| true);
+    assert!(solution.unwrap().is_unique());
+}
+
+#[test]
+fn goals_without_matching_clauses_fail_immediately() {
+    let (db, goal) = db_and_goal(
+        "trait Bar { } struct Z { }",
+        SolverChoice::slg_default(),
+        "Z: Bar",
+    );
+
+    // `Bar` has no impls, and without any assumptions the `FromEnv` rule
+    // can't apply either, so the engine has no clause to try and never
+    // gets to ask whether it should continue.
+    let calls = Cell::new(0);
+    let solution = SolverChoice::slg_default()
+        .into_solver()
+        .solve_limited(&db, &goal, &|| {
+            calls.set(calls.get() + 1);
+            true
+        });
+    assert_eq!(solution, None);
+    assert_eq!(calls.get(), 0);
+}
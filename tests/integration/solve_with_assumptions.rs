@@ -0,0 +1,59 @@
+use super::util::{peeled_goal, trait_id};
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::query::LoweringDatabase;
+use chalk_integration::SolverChoice;
+use chalk_ir::cast::Cast;
+use chalk_ir::*;
+
+#[test]
+fn assumptions_are_added_transiently() {
+    let interner = ChalkIr;
+    for solver_choice in [
+        SolverChoice::slg_default(),
+        SolverChoice::recursive_default(),
+    ] {
+        let db = ChalkDatabase::with(
+            "
+            trait Clone { }
+            trait Copy where Self: Clone { }
+            ",
+            solver_choice,
+        );
+        let program = db.program_ir().unwrap();
+        // Peeling the `forall` turns `T` into the placeholder `!1_0`.
+        let goal = peeled_goal(&db, "forall<T> { T: Clone }");
+        let placeholder = TyKind::Placeholder(PlaceholderIndex {
+            ui: UniverseIndex { counter: 1 },
+            idx: 0,
+        })
+        .intern(interner);
+        let assumption = |trait_name: &str| -> ProgramClause<ChalkIr> {
+            TraitRef {
+                trait_id: trait_id(&program, trait_name),
+                substitution: Substitution::from1(interner, placeholder.clone()),
+            }
+            .cast(interner)
+        };
+
+        let mut solver = solver_choice.into_solver();
+        let solution = solver.solve_with_assumptions(&db, &goal, &[assumption("Clone")]);
+        assert!(
+            solution.unwrap().is_unique(),
+            "using solver: {:?}",
+            solver_choice
+        );
+
+        // The assumptions are elaborated like the rest of the environment.
+        let solution = solver.solve_with_assumptions(&db, &goal, &[assumption("Copy")]);
+        assert!(
+            solution.unwrap().is_unique(),
+            "using solver: {:?}",
+            solver_choice
+        );
+
+        // The environment of `goal` itself is left untouched.
+        assert!(goal.canonical.value.environment.clauses.is_empty(interner));
+        assert_eq!(solver.solve(&db, &goal), None);
+    }
+}
@@ -0,0 +1,43 @@
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::query::LoweringDatabase;
+use chalk_integration::SolverChoice;
+use chalk_ir::*;
+use chalk_solve::coherence::more_specialized_than;
+
+#[test]
+fn specific_impl_is_more_specialized_than_blanket_impl() {
+    let db = ChalkDatabase::with(
+        "
+        trait Trait { }
+        impl<T> Trait for T { }
+        impl Trait for i32 { }
+        ",
+        SolverChoice::default(),
+    );
+    let program = db.program_ir().unwrap();
+    let (blanket, specific): (Vec<_>, Vec<_>) = program.impl_data.iter().partition(|(_, datum)| {
+        let self_ty = datum
+            .binders
+            .skip_binders()
+            .trait_ref
+            .self_type_parameter(ChalkIr);
+        matches!(self_ty.kind(ChalkIr), TyKind::BoundVar(_))
+    });
+    let blanket = *blanket[0].0;
+    let specific = *specific[0].0;
+
+    let solver_builder = || SolverChoice::default().into_solver();
+    assert!(more_specialized_than(
+        specific,
+        blanket,
+        &*program,
+        &solver_builder
+    ));
+    assert!(!more_specialized_than(
+        blanket,
+        specific,
+        &*program,
+        &solver_builder
+    ));
+}
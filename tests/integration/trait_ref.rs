@@ -0,0 +1,54 @@
+use super::util::db_and_goal;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::SolverChoice;
+use chalk_ir::*;
+
+/// Lowers `goal_text`, which must be a trait goal, and returns its trait ref.
+fn trait_ref(program_text: &str, goal_text: &str) -> TraitRef<ChalkIr> {
+    let (_, goal) = db_and_goal(program_text, SolverChoice::default(), goal_text);
+    match goal.canonical.value.goal.data(ChalkIr) {
+        GoalData::DomainGoal(DomainGoal::Holds(WhereClause::Implemented(trait_ref))) => {
+            trait_ref.clone()
+        }
+        goal => panic!("not a trait goal: {:?}", goal),
+    }
+}
+
+fn scalar(scalar: Scalar) -> Ty<ChalkIr> {
+    TyKind::Scalar(scalar).intern(ChalkIr)
+}
+
+#[test]
+fn self_type_parameter_of_single_parameter_trait() {
+    let trait_ref = trait_ref("trait Clone { }", "u8: Clone");
+    assert_eq!(
+        trait_ref.self_type_parameter(ChalkIr),
+        scalar(Scalar::Uint(UintTy::U8))
+    );
+    assert_eq!(
+        trait_ref.type_parameters(ChalkIr).collect::<Vec<_>>(),
+        [scalar(Scalar::Uint(UintTy::U8))]
+    );
+}
+
+#[test]
+fn type_parameters_of_multi_parameter_trait() {
+    // Lifetime parameters are skipped, but `Self` always comes first.
+    let trait_ref = trait_ref(
+        "trait Convert<'a, T, U> { }",
+        "forall<'a> { u8: Convert<'a, u32, bool> }",
+    );
+    assert_eq!(
+        trait_ref.self_type_parameter(ChalkIr),
+        scalar(Scalar::Uint(UintTy::U8))
+    );
+    assert_eq!(
+        trait_ref.type_parameters(ChalkIr).collect::<Vec<_>>(),
+        [
+            scalar(Scalar::Uint(UintTy::U8)),
+            scalar(Scalar::Uint(UintTy::U32)),
+            scalar(Scalar::Bool),
+        ]
+    );
+    assert_eq!(trait_ref.substitution.len(ChalkIr), 4);
+}
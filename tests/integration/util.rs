@@ -0,0 +1,44 @@
+//! Utilities shared by the tests that drive a `ChalkDatabase` directly, for
+//! the APIs the `test!` macro can't reach.
+
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::program::Program;
+use chalk_integration::{Identifier, SolverChoice};
+use chalk_ir::{Goal, InEnvironment, TraitId, UCanonical};
+use chalk_solve::ext::GoalExt;
+
+pub type UCanonicalGoal = UCanonical<InEnvironment<Goal<ChalkIr>>>;
+
+/// Lowers `goal_text` against the program of `db`, turning its outer
+/// quantifiers into placeholders and inference variables, as the solvers
+/// expect.
+pub fn peeled_goal(db: &ChalkDatabase, goal_text: &str) -> UCanonicalGoal {
+    db.parse_and_lower_goal(goal_text)
+        .unwrap()
+        .into_peeled_goal(ChalkIr)
+}
+
+/// Lowers `goal_text` against the program of `db`, keeping its quantifiers.
+pub fn closed_goal(db: &ChalkDatabase, goal_text: &str) -> UCanonicalGoal {
+    db.parse_and_lower_goal(goal_text)
+        .unwrap()
+        .into_closed_goal(ChalkIr)
+}
+
+/// A database for `program_text` together with `goal_text` lowered by
+/// `peeled_goal`.
+pub fn db_and_goal(
+    program_text: &str,
+    solver_choice: SolverChoice,
+    goal_text: &str,
+) -> (ChalkDatabase, UCanonicalGoal) {
+    let db = ChalkDatabase::with(program_text, solver_choice);
+    let goal = peeled_goal(&db, goal_text);
+    (db, goal)
+}
+
+/// The id of the trait called `name` in `program`.
+pub fn trait_id(program: &Program, name: &str) -> TraitId<ChalkIr> {
+    program.trait_ids[&Identifier::from(name)]
+}
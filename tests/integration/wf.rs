@@ -0,0 +1,110 @@
+use chalk_integration::db::ChalkDatabase;
+use chalk_integration::interner::ChalkIr;
+use chalk_integration::query::LoweringDatabase;
+use chalk_integration::{tls, SolverChoice};
+use chalk_solve::wf::WfError;
+
+fn unsatisfied_obligations(program_text: &str) -> Vec<String> {
+    let db = ChalkDatabase::with(program_text, SolverChoice::default());
+    let program = db.program_ir().unwrap();
+    let impl_id = *program.impl_data.keys().next().unwrap();
+    let obligations = db.unsatisfied_impl_obligations(impl_id).unwrap();
+    tls::set_current_program(&program, || {
+        obligations
+            .iter()
+            .map(|goal| format!("{:?}", goal))
+            .collect()
+    })
+}
+
+#[test]
+fn unsatisfied_impl_obligations_name_missing_bound() {
+    let obligations = unsatisfied_obligations(
+        "
+        trait Clone { }
+        trait Eq { }
+        trait Copy where Self: Clone, Self: Eq { }
+        struct Foo<T> { }
+        impl<T> Copy for Foo<T> where T: Eq { }
+        impl<T> Eq for Foo<T> { }
+        ",
+    );
+    assert_eq!(
+        obligations,
+        ["ForAll<type> { if ([for<> FromEnv(^1.0: Eq), for<> FromEnv(Foo<^1.0>)]) { ForAll<> { WellFormed(Foo<^1.0>: Clone) } } }"]
+    );
+}
+
+#[test]
+fn unsatisfied_impl_obligations_empty_for_wf_impl() {
+    let obligations = unsatisfied_obligations(
+        "
+        trait Clone { }
+        trait Copy where Self: Clone { }
+        struct Foo { }
+        impl Copy for Foo { }
+        impl Clone for Foo { }
+        ",
+    );
+    assert!(obligations.is_empty(), "{:?}", obligations);
+}
+
+/// The self types of the `Copy` impls flagged by `check_copy_drop_conflict`.
+fn copy_drop_conflicts(program_text: &str) -> Vec<String> {
+    let db = ChalkDatabase::with(program_text, SolverChoice::default());
+    let program = db.program_ir().unwrap();
+    let errors = db.check_copy_drop_conflict().unwrap();
+    tls::set_current_program(&program, || {
+        errors
+            .iter()
+            .map(|error| match error {
+                WfError::CopyImplNeedsDrop(impl_id) => {
+                    let trait_ref = &program.impl_data[impl_id].binders.skip_binders().trait_ref;
+                    format!("{:?}", trait_ref.self_type_parameter(ChalkIr))
+                }
+                _ => panic!("unexpected error: {}", error),
+            })
+            .collect()
+    })
+}
+
+#[test]
+fn copy_struct_with_drop_field_is_flagged() {
+    let conflicts = copy_drop_conflicts(
+        "
+        #[lang(copy)]
+        trait Copy { }
+        #[lang(drop)]
+        trait Drop { }
+
+        struct Noisy { }
+        impl Drop for Noisy { }
+
+        struct Wrapper { n: Noisy }
+        impl Copy for Wrapper { }
+
+        struct Pair { a: u32, b: (u32, [Noisy; 2]) }
+        impl Copy for Pair { }
+
+        struct Plain { a: u32, b: &'static Noisy }
+        impl Copy for Plain { }
+        ",
+    );
+    assert_eq!(conflicts, ["Wrapper", "Pair"]);
+}
+
+#[test]
+fn copy_drop_conflict_needs_both_lang_items() {
+    let conflicts = copy_drop_conflicts(
+        "
+        #[lang(copy)]
+        trait Copy { }
+        trait Drop { }
+
+        struct Noisy { }
+        impl Drop for Noisy { }
+        impl Copy for Noisy { }
+        ",
+    );
+    assert!(conflicts.is_empty(), "{:?}", conflicts);
+}
@@ -1,6 +1,8 @@
 use chalk_integration::db::ChalkDatabase;
 use chalk_integration::query::LoweringDatabase;
 use chalk_integration::SolverChoice;
+use chalk_solve::rust_ir::WellKnownTrait;
+use chalk_solve::RustIrDatabase;
 
 #[test]
 fn lower_success() {
@@ -813,3 +815,64 @@ fn algebraic_data_types() {
         }
     }
 }
+
+#[test]
+fn possible_impls_for_trait() {
+    let db = ChalkDatabase::with(
+        "
+            trait Clone { }
+            #[auto] trait Send { }
+
+            struct A { }
+            struct B<T> { }
+            struct C { }
+
+            impl Clone for A { }
+            impl<T> Clone for B<T> where T: Clone { }
+
+            impl !Send for C { }
+        ",
+        SolverChoice::default(),
+    );
+    db.with_program(|program| {
+        let heads = |name: &str| {
+            let trait_id = program
+                .trait_ids
+                .iter()
+                .find(|(trait_name, _)| &***trait_name == name)
+                .map(|(_, &trait_id)| trait_id)
+                .unwrap();
+            program
+                .possible_impls_for_trait(trait_id)
+                .iter()
+                .map(|head| format!("{:?}", head))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            heads("Clone"),
+            ["for<> A as Clone", "for<type> B<^0.0> as Clone"]
+        );
+        assert_eq!(
+            heads("Send"),
+            ["for<> A as Send", "for<type> B<^0.0> as Send"]
+        );
+    });
+}
+
+#[test]
+fn well_known_traits() {
+    let db = ChalkDatabase::with(
+        "
+            trait Sized { }
+            #[lang(copy)] trait Copy { }
+        ",
+        SolverChoice::default(),
+    );
+
+    // Only `#[lang]` traits are well known, whatever their name
+    assert_eq!(db.well_known_trait_id(WellKnownTrait::Sized), None);
+    assert_eq!(db.well_known_trait_id(WellKnownTrait::Clone), None);
+    let copy = db.well_known_trait_id(WellKnownTrait::Copy).unwrap();
+    assert_eq!(db.trait_datum(copy).well_known, Some(WellKnownTrait::Copy));
+}
@@ -0,0 +1,166 @@
+use super::*;
+
+#[test]
+fn struct_sized_depends_on_last_field() {
+    test! {
+        program {
+            #[lang(sized)]
+            trait Sized { }
+
+            struct Foo<T> {
+                a: u32,
+                b: T
+            }
+        }
+
+        goal {
+            Foo<u32>: Sized
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Foo<[u32]>: Sized
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { Foo<T>: Sized }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Sized) { Foo<T>: Sized } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn struct_sized_ignores_leading_fields() {
+    test! {
+        program {
+            #[lang(sized)]
+            trait Sized { }
+
+            struct Foo<T> where T: Sized {
+                a: T,
+                b: u32
+            }
+        }
+
+        goal {
+            forall<T> { Foo<T>: Sized }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn fieldless_struct_is_sized() {
+    test! {
+        program {
+            #[lang(sized)]
+            trait Sized { }
+
+            struct Foo { }
+        }
+
+        goal {
+            Foo: Sized
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn structs_are_copy_only_with_explicit_impl() {
+    test! {
+        program {
+            #[lang(copy)]
+            trait Copy { }
+
+            impl Copy for u32 { }
+
+            struct NotCopy { }
+
+            struct NoImpl {
+                a: u32
+            }
+
+            struct Pair<T> {
+                a: u32,
+                b: T
+            }
+            impl<T> Copy for Pair<T> where T: Copy { }
+        }
+
+        goal {
+            NoImpl: Copy
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            Pair<u32>: Copy
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Pair<NotCopy>: Copy
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
+#[test]
+fn derived_clone_is_conditional_on_type_parameters() {
+    test! {
+        program {
+            #[lang(clone)]
+            trait Clone { }
+
+            impl Clone for u32 { }
+
+            struct NotClone { }
+
+            // What `#[derive(Clone)]` would generate
+            struct Pair<T, U> {
+                a: T,
+                b: U
+            }
+            impl<T, U> Clone for Pair<T, U> where T: Clone, U: Clone { }
+        }
+
+        goal {
+            Pair<u32, u32>: Clone
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Pair<u32, NotClone>: Clone
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Clone) { Pair<T, u32>: Clone } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            NotClone: Clone
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
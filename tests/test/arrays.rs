@@ -136,3 +136,72 @@ fn arrays_are_well_formed_if_elem_sized() {
         }
     }
 }
+
+#[test]
+fn concrete_length_arrays_are_copy() {
+    test! {
+        program {
+            #[lang(copy)]
+            trait Copy { }
+
+            impl Copy for u32 { }
+        }
+
+        goal {
+            [u32; 4]: Copy
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            exists<const N> {
+                [u32; N]: Copy
+            }
+        } yields {
+            expect![["Unique; for<?U0> { substitution [?0 := ^0.0] }"]]
+        }
+    }
+}
+
+#[test]
+fn arrays_and_slices_implement_auto_traits() {
+    test! {
+        program {
+            #[auto] trait Send { }
+
+            struct Foo { }
+            struct Bar { }
+            impl !Send for Bar { }
+        }
+
+        goal {
+            [Foo; 3]: Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            [Bar; 3]: Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            [Foo]: Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            [[Bar; 2]]: Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T, const N> { if (T: Send) { [T; N]: Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use super::*;
+
+#[test]
+fn assoc_const_in_concrete_impl() {
+    test! {
+        program {
+            trait Foo {
+                const N: usize;
+            }
+
+            struct Bar { }
+
+            impl Foo for Bar {
+                const N = 3;
+            }
+        }
+
+        goal {
+            ConstEq(<Bar as Foo>::N = 3)
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            ConstEq(<Bar as Foo>::N = 4)
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            exists<const C> {
+                ConstEq(<Bar as Foo>::N = C)
+            }
+        } yields {
+            expect![["Unique; substitution [?0 := 3]"]]
+        }
+    }
+}
+
+#[test]
+fn assoc_const_in_generic_impl() {
+    test! {
+        program {
+            trait Foo {
+                const N: usize;
+            }
+
+            trait Marker { }
+
+            struct Bar<T> { }
+            struct Baz { }
+            struct Qux { }
+
+            impl Marker for Baz { }
+
+            impl<T> Foo for Bar<T> where T: Marker {
+                const N = 7;
+            }
+        }
+
+        goal {
+            ConstEq(<Bar<Baz> as Foo>::N = 7)
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            ConstEq(<Bar<Qux> as Foo>::N = 7)
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> {
+                if (T: Marker) {
+                    ConstEq(<Bar<T> as Foo>::N = 7)
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn assoc_const_missing_from_trait() {
+    lowering_error! {
+        program {
+            trait Foo { }
+
+            struct Bar { }
+
+            impl Foo for Bar {
+                const N = 3;
+            }
+        }
+        error_msg {
+            "no associated const `N` defined in trait"
+        }
+    }
+}
+
+#[test]
+fn assoc_const_missing_from_impl() {
+    lowering_error! {
+        program {
+            trait Foo {
+                const N: usize;
+            }
+
+            struct Bar { }
+
+            impl Foo for Bar { }
+        }
+        error_msg {
+            "impl of trait `Foo` does not provide associated const `N`"
+        }
+    }
+
+    // Negative impls don't provide values.
+    lowering_success! {
+        program {
+            trait Foo {
+                const N: usize;
+            }
+
+            struct Bar { }
+
+            impl !Foo for Bar { }
+        }
+    }
+}
+
+#[test]
+fn assoc_const_value_of_wrong_type() {
+    lowering_error! {
+        program {
+            trait Foo {
+                const N: u32;
+            }
+
+            struct Bar { }
+
+            impl Foo for Bar {
+                const N = 3;
+            }
+        }
+        error_msg {
+            "value of associated const `N` does not have the type declared in the trait"
+        }
+    }
+
+    // The declared type is checked once the trait's parameters are
+    // substituted.
+    lowering_error! {
+        program {
+            trait Foo<T> {
+                const N: T;
+            }
+
+            struct Bar { }
+
+            impl<T> Foo<T> for Bar {
+                const N = 3;
+            }
+        }
+        error_msg {
+            "value of associated const `N` does not have the type declared in the trait"
+        }
+    }
+
+    lowering_success! {
+        program {
+            trait Foo<T> {
+                const N: T;
+            }
+
+            struct Bar<const C> { }
+
+            impl<const C> Foo<usize> for Bar<C> {
+                const N = C;
+            }
+        }
+    }
+}
@@ -53,6 +53,60 @@ fn auto_semantics() {
     }
 }
 
+/// Proving `Foo: Send` requires `Box<Foo>: Send`, which in turn
+/// requires `Foo: Send` again. Auto traits are coinductive, so these
+/// cycles succeed.
+#[test]
+fn auto_trait_self_referential_struct() {
+    test! {
+        program {
+            #[auto] trait Send { }
+
+            struct Box<T> {
+                value: T
+            }
+
+            struct Foo {
+                next: Box<Foo>
+            }
+
+            struct Even {
+                next: Box<Odd>
+            }
+
+            struct Odd {
+                next: Box<Even>
+            }
+
+            struct NotSend { }
+            impl !Send for NotSend { }
+
+            struct Bad {
+                next: Box<Bad>,
+                data: NotSend
+            }
+        }
+
+        goal {
+            Foo: Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Even: Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Bad: Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
 #[test]
 fn auto_trait_without_impls() {
     test! {
@@ -143,16 +197,147 @@ fn auto_trait_with_impls() {
     }
 }
 
-/// This Flounders because auto traits can't be enumerated
 #[test]
-fn auto_traits_flounder() {
+fn negative_impl_propagates_through_fields() {
+    test! {
+        program {
+            #[auto] trait Send { }
+            #[auto] trait Sync { }
+
+            struct Cell<T> {
+                value: T
+            }
+            impl<T> !Sync for Cell<T> { }
+
+            struct Wrapper<T> {
+                inner: Cell<T>
+            }
+
+            struct Outer<T> {
+                wrapper: Wrapper<T>
+            }
+
+            struct Foo { }
+        }
+
+        goal {
+            Cell<Foo>: Sync
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            Wrapper<Foo>: Sync
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            Outer<Foo>: Sync
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        // The negative impl only affects `Sync`.
+        goal {
+            Outer<Foo>: Send
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn negative_auto_trait_impl_proves_negated_goal() {
+    test! {
+        program {
+            #[auto] trait Send { }
+
+            struct Foo { }
+            struct Bar<T> { }
+            struct Wrapper<T> { t: T }
+
+            impl !Send for Foo { }
+            impl<T> !Send for Bar<T> { }
+        }
+
+        goal {
+            not { Foo: Send }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            not { Wrapper<Foo>: Send }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { not { Bar<T>: Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { if (T: Send) { Bar<T>: Send } }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            not { Wrapper<u32>: Send }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
+/// An open goal enumerates the ADTs implementing the auto trait, but can't
+/// rule out other types, so it flounders once the ADTs are exhausted.
+#[test]
+fn auto_traits_enumerate_adts() {
     test! {
         program {
             struct Foo { }
             struct Bar { }
+            struct Baz { }
 
             #[auto]
             trait Send { }
+
+            impl !Send for Baz { }
+        }
+
+        // `Baz` is not a candidate because of its negative impl. The types
+        // that aren't ADTs can't be enumerated, so after `Foo` and `Bar` the
+        // goal flounders, and the recursive solver is left without guidance.
+        goal {
+            exists<A> { A: Send }
+        } yields_all[SolverChoice::slg(3, None)] {
+            expect![["substitution [?0 := Foo]"]],
+            expect![["substitution [?0 := Bar]"]],
+            expect![["Floundered"]]
+        }
+
+        goal {
+            exists<A> { A: Send }
+        } yields {
+            expect![["Ambiguous; no inference guidance"]]
+        }
+    }
+}
+
+/// Non-enumerable auto traits still flounder.
+#[test]
+fn non_enumerable_auto_traits_flounder() {
+    test! {
+        program {
+            struct Foo { }
+
+            #[auto]
+            #[non_enumerable]
+            trait Send { }
         }
 
         goal {
@@ -368,3 +553,101 @@ fn phantom_auto_trait() {
         }
     }
 }
+
+#[test]
+fn phantom_data_field_auto_trait() {
+    test! {
+        program {
+            #[auto] trait Send {}
+
+            #[variance(Covariant)]
+            #[phantom_data]
+            struct PhantomData<T> {}
+
+            struct Foo<T> {
+                phantom: PhantomData<T>
+            }
+
+            struct Good {}
+            struct Bad {}
+            impl !Send for Bad {}
+        }
+
+        goal {
+            Foo<Good>: Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Foo<Bad>: Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { Foo<T>: Send }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Send) { Foo<T>: Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'a, 'b> { Subtype(PhantomData<&'a u32>, PhantomData<&'b u32>) }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: '!1_0: '!1_1 }]"]]
+        }
+    }
+}
+
+#[test]
+fn specific_impl_does_not_suppress_auto_impl() {
+    test! {
+        program {
+            #[auto] trait Send { }
+
+            struct NotSend { }
+            impl !Send for NotSend { }
+
+            struct Foo<T> { field: T }
+            impl Send for Foo<NotSend> { }
+
+            struct Bar<T> { field: T }
+            impl<T> Send for Bar<T> where T: Copy { }
+
+            trait Copy { }
+        }
+
+        // The specific impl applies to `Foo<NotSend>` only...
+        goal {
+            Foo<NotSend>: Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // ...while other instances still get the auto impl.
+        goal {
+            Foo<u32>: Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Foo<Foo<u32>>: Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // A blanket impl replaces the auto impl entirely.
+        goal {
+            Bar<u32>: Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
@@ -147,6 +147,47 @@ fn closure_is_clone() {
     }
 }
 
+#[test]
+fn closure_is_send_if_upvars_are_send() {
+    test! {
+        program {
+            #[auto] trait Send { }
+
+            struct NotSend { }
+            impl !Send for NotSend { }
+
+            closure send_upvars<'a>(self, a: NotSend) {
+                u32;
+                &'a u32
+            }
+            closure not_send_upvars(self,) {
+                u32;
+                NotSend
+            }
+            closure with_ty<T>(self,) { T }
+        }
+
+        // Only upvars matter, not the closure's arguments
+        goal {
+            forall<'a> {
+                send_upvars<'a>: Send
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+        goal {
+            not_send_upvars: Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+        goal {
+            forall<T> { if (T: Send) { with_ty<T>: Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
 #[test]
 fn closure_implements_fn_traits() {
     test! {
@@ -40,6 +40,34 @@ fn generic_vec_and_specific_vec() {
     }
 }
 
+#[test]
+fn specific_and_generic_impls_of_lang_trait() {
+    // The concrete impl specializes the generic one, so this is accepted.
+    lowering_success! {
+        program {
+            #[lang(clone)]
+            trait Clone { }
+            struct Foo<T> { }
+            impl Clone for Foo<i32> { }
+            impl<T> Clone for Foo<T> { }
+        }
+    }
+
+    // Both impls apply to `Foo<i32, i32>`, but neither is more specific.
+    lowering_error! {
+        program {
+            #[lang(clone)]
+            trait Clone { }
+            struct Foo<T, U> { }
+            impl<T> Clone for Foo<T, i32> { }
+            impl<U> Clone for Foo<i32, U> { }
+        }
+        error_msg {
+            "overlapping impls of trait `Clone`"
+        }
+    }
+}
+
 #[test]
 fn concrete_impl_and_blanket_impl() {
     lowering_success! {
@@ -97,6 +125,23 @@ fn two_blanket_impls_open_ended() {
     }
 }
 
+#[test]
+fn overlapping_impls_of_marker_trait() {
+    lowering_success! {
+        program {
+            #[marker] trait Marker { }
+            trait Foo { }
+            trait Bar { }
+            struct Baz { }
+
+            impl Marker for Baz { }
+            impl Marker for Baz { }
+            impl<T> Marker for T where T: Foo { }
+            impl<T> Marker for T where T: Bar { }
+        }
+    }
+}
+
 #[test]
 fn multiple_nonoverlapping_impls() {
     lowering_success! {
@@ -517,6 +562,136 @@ fn orphan_check() {
     }
 }
 
+#[test]
+fn orphan_check_accepts_local_trait_or_local_type() {
+    // A local trait may be implemented for any type.
+    lowering_success! {
+        program {
+            #[upstream] struct Foreign { }
+            trait Local { }
+
+            impl Local for Foreign { }
+            impl<T> Local for T { }
+        }
+    }
+
+    // A foreign trait may be implemented for a local type, including
+    // when the trait's type parameters are foreign.
+    lowering_success! {
+        program {
+            #[upstream] trait Remote<T> { }
+            #[upstream] struct Foreign { }
+            struct Local<T> { }
+
+            impl Remote<Foreign> for Local<Foreign> { }
+            impl<T> Remote<Local<T>> for Foreign { }
+        }
+    }
+}
+
+#[test]
+fn orphan_check_multi_param_trait_ordering() {
+    // The first local type may be preceded by foreign types, and followed
+    // by uncovered type parameters.
+    lowering_success! {
+        program {
+            #[upstream] trait Remote2<A, B> { }
+            #[upstream] struct Foreign { }
+            struct Local { }
+
+            impl<T> Remote2<T, Foreign> for Local { }
+            impl<T> Remote2<Local, T> for Foreign { }
+        }
+    }
+
+    // But no uncovered type parameter may come before it.
+    lowering_error! {
+        program {
+            #[upstream] trait Remote2<A, B> { }
+            struct Local { }
+
+            impl<T> Remote2<Local, Local> for T { }
+        } error_msg {
+            "impl for trait `Remote2` violates the orphan rules"
+        }
+    }
+
+    lowering_error! {
+        program {
+            #[upstream] trait Remote2<A, B> { }
+            #[upstream] struct Foreign { }
+            struct Local { }
+
+            impl<T> Remote2<T, Local> for Foreign { }
+        } error_msg {
+            "impl for trait `Remote2` violates the orphan rules"
+        }
+    }
+}
+
+#[test]
+fn fundamental_box_of_local_type() {
+    lowering_success! {
+        program {
+            #[upstream]
+            #[fundamental]
+            struct Box<T> { }
+
+            #[upstream]
+            trait Remote { }
+
+            struct Local { }
+
+            impl Remote for Box<Local> { }
+        }
+    }
+
+    lowering_error! {
+        program {
+            #[upstream]
+            #[fundamental]
+            struct Box<T> { }
+
+            #[upstream]
+            trait Remote { }
+
+            #[upstream]
+            struct Foreign { }
+
+            impl Remote for Box<Foreign> { }
+        } error_msg {
+            "impl for trait `Remote` violates the orphan rules"
+        }
+    }
+
+    // References are fundamental too
+    lowering_success! {
+        program {
+            #[upstream]
+            trait Remote { }
+
+            struct Local { }
+
+            impl<'a> Remote for &'a Local { }
+            impl<'a> Remote for &'a mut Local { }
+        }
+    }
+
+    lowering_error! {
+        program {
+            #[upstream]
+            trait Remote { }
+
+            #[upstream]
+            struct Foreign { }
+
+            impl<'a> Remote for &'a Foreign { }
+        } error_msg {
+            "impl for trait `Remote` violates the orphan rules"
+        }
+    }
+}
+
 #[test]
 fn fundamental_type_multiple_parameters() {
     // Test that implementing a local trait on a fundamental
@@ -34,6 +34,20 @@ fn local_and_upstream_types() {
         goal { forall<T> { IsLocal(Local<T>) } } yields { expect![["Unique"]] }
         goal { forall<T> { IsUpstream(Local<T>) } } yields { expect![["No possible solution"]] }
     }
+
+    // References are fundamental
+    test! {
+        program {
+            #[upstream] struct Upstream { }
+            struct Local { }
+        }
+
+        goal { forall<'a> { IsLocal(&'a Local) } } yields { expect![["Unique"]] }
+        goal { forall<'a> { IsUpstream(&'a Local) } } yields { expect![["No possible solution"]] }
+
+        goal { forall<'a> { IsLocal(&'a mut Upstream) } } yields { expect![["No possible solution"]] }
+        goal { forall<'a> { IsUpstream(&'a mut Upstream) } } yields { expect![["Unique"]] }
+    }
 }
 
 #[test]
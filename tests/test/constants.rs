@@ -147,3 +147,52 @@ fn placeholders_eq() {
         }
     }
 }
+
+#[test]
+fn projection_with_const_params() {
+    test! {
+        program {
+            struct S<T, const N> {}
+            struct A {}
+            struct B {}
+
+            trait Trait {
+                type Assoc;
+            }
+
+            impl<T> Trait for S<T, 3> {
+                type Assoc = A;
+            }
+
+            impl<T> Trait for S<T, 5> {
+                type Assoc = B;
+            }
+        }
+
+        goal {
+            exists<const N> {
+                Normalize(<S<A, N> as Trait>::Assoc -> B)
+            }
+        } yields {
+            expect![["Unique; substitution [?0 := 5]"]]
+        }
+
+        goal {
+            exists<const N> {
+                <S<A, N> as Trait>::Assoc = A
+            }
+        } yields {
+            expect![["Unique; substitution [?0 := 3]"]]
+        }
+
+        goal {
+            forall<const N> {
+                exists<U> {
+                    Normalize(<S<A, N> as Trait>::Assoc -> U)
+                }
+            }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
@@ -171,6 +171,68 @@ fn overflow() {
     }
 }
 
+#[test]
+fn overflow_depth() {
+    test! {
+        program {
+            trait T0 { }
+            trait T1 { }
+            trait T2 { }
+            trait T3 { }
+            trait T4 { }
+            trait T5 { }
+            trait T6 { }
+            trait T7 { }
+            struct Z { }
+
+            impl T0 for Z { }
+            impl<X> T1 for X where X: T0 { }
+            impl<X> T2 for X where X: T1 { }
+            impl<X> T3 for X where X: T2 { }
+            impl<X> T4 for X where X: T3 { }
+            impl<X> T5 for X where X: T4 { }
+            impl<X> T6 for X where X: T5 { }
+            impl<X> T7 for X where X: T6 { }
+
+            trait Pick { }
+            struct Foo<T> { }
+            impl<T> Pick for Foo<T> { }
+        }
+
+        // Proving `Z: T7` needs a stack of eight trait goals. With a smaller
+        // limit the recursive solver gives up, which is distinct from failing.
+        goal {
+            Z: T7
+        } yields[SolverChoice::recursive(30, 4)] {
+            expect![["Overflow"]]
+        } yields[SolverChoice::recursive(30, 100)] {
+            expect![["Unique"]]
+        }
+
+        // A goal that fails within the limit is still reported as failing...
+        goal {
+            u32: T2
+        } yields[SolverChoice::recursive(30, 4)] {
+            expect![["No possible solution"]]
+        }
+
+        // ...but one that would only fail beyond the limit overflows.
+        goal {
+            u32: T7
+        } yields[SolverChoice::recursive(30, 4)] {
+            expect![["Overflow"]]
+        }
+
+        // An overflowing obligation doesn't hide what the others taught us
+        // about the inference variables.
+        goal {
+            exists<X> { X: Pick, X: T7 }
+        } yields[SolverChoice::recursive(30, 4)] {
+            expect![["Ambiguous; definite substitution for<?U0> { [?0 := Foo<^0.0>] }"]]
+        }
+    }
+}
+
 #[test]
 fn overflow_universe() {
     test! {
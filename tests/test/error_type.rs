@@ -0,0 +1,98 @@
+//! Tests for `{error}`, the type that stands in for types which failed to
+//! lower, so that the rest of a partial program can still be queried.
+
+use super::*;
+
+#[test]
+fn error_type_is_well_formed() {
+    test! {
+        goal {
+            WellFormed({error})
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn error_type_has_no_builtin_impls() {
+    test! {
+        program {
+            #[lang(sized)]
+            trait Sized { }
+            #[lang(copy)]
+            trait Copy { }
+            #[auto] trait Send { }
+            trait Foo { }
+
+            impl<T> Foo for T { }
+        }
+
+        goal {
+            {error}: Sized
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            {error}: Copy
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        // Auto traits hold, as the error type has no constituent types
+        goal {
+            {error}: Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // Blanket impls still apply
+        goal {
+            {error}: Foo
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn error_type_in_partial_program() {
+    test! {
+        program {
+            trait Foo { }
+
+            struct Ok { }
+            struct Broken {
+                field: {error}
+            }
+
+            impl Foo for Ok { }
+            impl Foo for Broken { }
+        }
+
+        goal {
+            Ok: Foo
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Broken: Foo
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            {error} = {error}
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            {error} = Ok
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
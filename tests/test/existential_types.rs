@@ -410,6 +410,7 @@ fn dyn_associated_type_binding() {
 fn dyn_well_formed() {
     test! {
         program {
+            #[object_safe]
             trait MyTrait {}
         }
 
@@ -422,3 +423,67 @@ fn dyn_well_formed() {
         }
     }
 }
+
+#[test]
+fn dyn_well_formed_requires_object_safety() {
+    test! {
+        program {
+            #[object_safe]
+            trait Write {}
+            trait Serialize {}
+            #[auto]
+            trait Send {}
+        }
+
+        goal {
+            forall<'s> { WellFormed(dyn Write + Send + 's) }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'s> { WellFormed(dyn Serialize + 's) }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
+#[test]
+fn dyn_well_formed_requires_assoc_type_bindings() {
+    test! {
+        program {
+            trait Copy {}
+            #[object_safe]
+            trait Iterator { type Item; }
+            struct Foo {}
+            struct NeedsCopy<T> where T: Copy {}
+            impl Copy for Foo {}
+        }
+
+        goal {
+            forall<'s> { WellFormed(dyn Iterator<Item = Foo> + 's) }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'s> { WellFormed(dyn Iterator + 's) }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        // The bound type must be well-formed itself.
+        goal {
+            forall<'s> { WellFormed(dyn Iterator<Item = NeedsCopy<Foo>> + 's) }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'s> { WellFormed(dyn Iterator<Item = NeedsCopy<u32>> + 's) }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
@@ -44,6 +44,28 @@ fn functions_are_copy() {
     }
 }
 
+#[test]
+fn functions_are_clone() {
+    test! {
+        program {
+            #[lang(clone)]
+            trait Clone { }
+        }
+
+        goal {
+            fn(u8) -> bool: Clone
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { fn(T) -> T: Clone }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
 #[test]
 fn function_implement_fn_traits() {
     test! {
@@ -246,3 +268,51 @@ fn function_implement_fn_traits() {
         }
     }
 }
+
+#[test]
+fn function_output_is_declared_return_type() {
+    test! {
+        program {
+            #[lang(fn_once)]
+            trait FnOnce<Args> {
+                type Output;
+            }
+
+            #[lang(fn_mut)]
+            trait FnMut<Args> where Self: FnOnce<Args> { }
+
+            #[lang(fn)]
+            trait Fn<Args> where Self: FnMut<Args> { }
+        }
+
+        // `Output` is found through the `Fn` impl's supertrait
+        goal {
+            exists<U> {
+                fn(u8) -> bool: Fn<(u8,)>,
+                Normalize(<fn(u8) -> bool as FnOnce<(u8,)>>::Output -> U)
+            }
+        } yields {
+            expect![["Unique; substitution [?0 := Bool]"]]
+        }
+
+        // Late-bound lifetimes in the return type are instantiated from the
+        // arguments
+        goal {
+            exists<U> {
+                Normalize(<for<'a> fn(&'a u8) -> &'a u8 as FnOnce<(&'static u8,)>>::Output -> U)
+            }
+        } yields {
+            expect![["Unique; substitution [?0 := (&'static Uint(U8))]"]]
+        }
+
+        goal {
+            forall<A, R> {
+                exists<U> {
+                    Normalize(<fn(A) -> R as FnOnce<(A,)>>::Output -> U)
+                }
+            }
+        } yields {
+            expect![["Unique; substitution [?0 := !1_1]"]]
+        }
+    }
+}
@@ -117,6 +117,54 @@ fn implied_from_env() {
     }
 }
 
+#[test]
+fn impl_where_clauses_are_assumed_not_implied() {
+    test! {
+        program {
+            trait Clone { }
+            trait Foo { }
+            struct Bar<T> { }
+
+            impl<T> Foo for Bar<T> where T: Clone { }
+        }
+
+        // Inside the impl, its where clauses are part of the environment,
+        // so they hold as `FromEnv` facts.
+        goal {
+            forall<T> {
+                if (T: Clone) {
+                    FromEnv(T: Clone)
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> {
+                if (T: Clone) {
+                    Bar<T>: Foo
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // But knowing that the impl applies does not imply its where
+        // clauses: unlike trait and type where clauses, they are not
+        // reverse rules.
+        goal {
+            forall<T> {
+                if (FromEnv(Bar<T>: Foo)) {
+                    T: Clone
+                }
+            }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
 #[test]
 fn higher_ranked_implied_bounds() {
     test! {
@@ -157,3 +205,209 @@ fn higher_ranked_implied_bounds() {
         }
     }
 }
+
+#[test]
+fn higher_ranked_env_bound() {
+    test! {
+        program {
+            #[lang(fn_once)]
+            trait FnOnce<Args> { type Output; }
+
+            #[lang(fn_mut)]
+            trait FnMut<Args> where Self: FnOnce<Args> { }
+
+            #[lang(fn)]
+            trait Fn<Args> where Self: FnMut<Args> { }
+        }
+
+        // The bound can be instantiated at any lifetime...
+        goal {
+            forall<F> {
+                if (forall<'a> { F: Fn<(&'a u32,)> }) {
+                    F: Fn<(&'static u32,)>
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<F, 'x> {
+                if (forall<'a> { F: Fn<(&'a u32,)> }) {
+                    F: Fn<(&'x u32,)>
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // ...including through the elaborated supertraits.
+        goal {
+            forall<F, 'x> {
+                if (forall<'a> { F: Fn<(&'a u32,)> }) {
+                    F: FnOnce<(&'x u32,)>
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // A bound for one lifetime only gives a higher-ranked one if every
+        // lifetime is equal to it.
+        goal {
+            forall<F> {
+                if (F: Fn<(&'static u32,)>) {
+                    forall<'a> { F: Fn<(&'a u32,)> }
+                }
+            }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: '!2_0: 'static }, InEnvironment { environment: Env([]), goal: 'static: '!2_0 }]"]]
+        }
+    }
+}
+
+#[test]
+fn cyclic_elaboration_terminates() {
+    test! {
+        program {
+            trait A where Self: B { }
+            trait B where Self: C<Self> { }
+            trait C<T> where T: A { type Item: A; }
+        }
+
+        goal {
+            forall<T> {
+                if (T: A) {
+                    T: C<T>
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> {
+                if (T: B) {
+                    <T as C<T>>::Item: B
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn assoc_type_bounds_are_implied() {
+    test! {
+        program {
+            trait Clone { }
+            trait Iterator {
+                type Item: Clone;
+            }
+        }
+
+        goal {
+            forall<T> {
+                if (T: Iterator) {
+                    <T as Iterator>::Item: Clone
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T, U> {
+                if (T: Iterator<Item = U>) {
+                    U: Clone
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // Without `T: Iterator` in the environment, nothing is known about
+        // the projection.
+        goal {
+            forall<T> {
+                <T as Iterator>::Item: Clone
+            }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
+#[test]
+fn supertrait_from_env() {
+    test! {
+        program {
+            trait Eq { }
+            trait Ord where Self: Eq { }
+        }
+
+        // `FromEnv(T: Eq) :- FromEnv(T: Ord)`
+        goal {
+            forall<T> {
+                if (FromEnv(T: Ord)) {
+                    T: Eq
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // The reverse direction does not hold.
+        goal {
+            forall<T> {
+                if (FromEnv(T: Eq)) {
+                    T: Ord
+                }
+            }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
+#[test]
+fn higher_ranked_env_self_type_elaborates_supertraits() {
+    test! {
+        program {
+            trait Super { }
+            trait Foo where Self: Super { }
+            trait Other { }
+        }
+
+        goal {
+            forall<T> {
+                if (forall<'a> { FromEnv(&'a T: Foo) }) {
+                    forall<'b> { &'b T: Super }
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> {
+                if (forall<'a> { FromEnv(&'a T: Foo) }) {
+                    &'static T: Super
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> {
+                if (forall<'a> { FromEnv(&'a T: Foo) }) {
+                    forall<'b> { &'b T: Other }
+                }
+            }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
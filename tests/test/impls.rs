@@ -591,6 +591,34 @@ fn partial_overlap_3() {
     }
 }
 
+#[test]
+fn marker_trait_duplicate_impls() {
+    test! {
+        program {
+            #[marker] trait Marker {}
+            struct Struct {}
+            struct Vec<T> {}
+
+            impl Marker for Struct {}
+            impl Marker for Struct {}
+            impl<T> Marker for Vec<T> {}
+            impl Marker for Vec<Struct> {}
+        }
+
+        goal {
+            Struct: Marker
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Vec<Struct>: Marker
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
 #[test]
 fn clauses_in_if_goals() {
     test! {
@@ -677,3 +705,54 @@ fn unify_types_in_impl() {
         }
     }
 }
+
+#[test]
+fn multiple_impls_are_ambiguous() {
+    test! {
+        program {
+            trait From<T> {}
+            struct Vec<T> {}
+            impl From<i32> for u64 {}
+            impl From<i32> for i64 {}
+            impl From<u8> for Vec<u8> {}
+            impl From<u8> for Vec<u16> {}
+        }
+
+        // Neither impl is picked over the other.
+        goal {
+            exists<X> { X: From<i32> }
+        } yields {
+            expect![["Ambiguous; no inference guidance"]]
+        }
+
+        goal {
+            exists<X> { X: From<i32> }
+        } yields_all[SolverChoice::slg(10, None)] {
+            expect![["substitution [?0 := Uint(U64)]"]],
+            expect![["substitution [?0 := Int(I64)]"]]
+        }
+
+        // The candidates only agree on `Vec`, which the SLG solver
+        // reports as guidance.
+        goal {
+            exists<X> { X: From<u8> }
+        } yields[SolverChoice::slg(10, None)] {
+            expect![["Ambiguous; definite substitution for<?U0> { [?0 := Vec<^0.0>] }"]]
+        } yields[SolverChoice::recursive_default()] {
+            expect![["Ambiguous; no inference guidance"]]
+        }
+
+        // Each candidate on its own is unique, and `i32` isn't `From<i32>`.
+        goal {
+            u64: From<i32>
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            i32: From<i32>
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
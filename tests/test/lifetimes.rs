@@ -181,3 +181,35 @@ fn empty_impls() {
         }
     }
 }
+
+#[test]
+fn outlives_from_env() {
+    test! {
+        program {
+            struct Foo<'a, T> { }
+        }
+
+        // Outlives goals are not decomposed by the solver; they are
+        // returned as region constraints for the caller's region
+        // inference to check against the assumed outlives facts.
+        goal {
+            forall<'a, 'b> {
+                if ('b: 'a) {
+                    &'b u32: 'a
+                }
+            }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: (&'!1_1 Uint(U32)): '!1_0 }]"]]
+        }
+
+        goal {
+            forall<'a, 'b, T> {
+                if (T: 'a; 'b: 'a) {
+                    Foo<'b, T>: 'a
+                }
+            }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: Foo<'!1_1, !1_2>: '!1_0 }]"]]
+        }
+    }
+}
@@ -826,3 +826,140 @@ fn env_bound_vars() {
         }
     }
 }
+
+#[test]
+fn recursive_solver_without_caching() {
+    // `yields` compares the SLG and (caching) recursive solvers; check that
+    // turning the recursive solver's cache off gives the same answers.
+    const UNCACHED: SolverChoice = SolverChoice::Recursive {
+        overflow_depth: 100,
+        caching_enabled: false,
+        max_size: 30,
+    };
+
+    test! {
+        program {
+            trait Foo { }
+            trait Bar { }
+            struct Vec<T> { }
+            struct Baz { }
+
+            impl Foo for Baz { }
+            impl<T> Foo for Vec<T> where T: Foo { }
+        }
+
+        goal {
+            Vec<Vec<Baz>>: Foo
+        } yields[UNCACHED] {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Vec<Baz>: Bar
+        } yields[UNCACHED] {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Foo) { Vec<T>: Foo } }
+        } yields[UNCACHED] {
+            expect![["Unique"]]
+        }
+
+        goal {
+            exists<T> { Vec<T>: Foo }
+        } yields[UNCACHED] {
+            expect![["Ambiguous; no inference guidance"]]
+        }
+    }
+}
+
+/// Type parameters carry no implicit `Sized` bound: the equivalent of
+/// `T: ?Sized` is the default, and a plain Rust `T` is lowered with an
+/// explicit `T: Sized` where clause.
+#[test]
+fn type_parameters_are_not_implicitly_sized() {
+    test! {
+        program {
+            #[lang(sized)]
+            trait Sized { }
+
+            struct Sized1<T> where T: Sized { }
+        }
+
+        goal {
+            forall<T> { T: Sized }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Sized) { T: Sized } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { WellFormed(Sized1<T>) }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Sized) { WellFormed(Sized1<T>) } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // Where clauses on types are implied bounds, so code using
+        // `Sized1<T>` may assume `T: Sized`.
+        goal {
+            forall<T> { if (FromEnv(Sized1<T>)) { T: Sized } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+/// Builtin rules are keyed on `#[lang]` attributes, not names: without
+/// a well-known `Sized` no builtin impls are generated for it, and the
+/// `Sized` requirements on slices and tuples are skipped.
+#[test]
+fn builtin_traits_need_lang_attribute() {
+    test! {
+        program {
+            trait Sized { }
+        }
+
+        goal {
+            u32: Sized
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            WellFormed([[u32]])
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+
+    test! {
+        program {
+            #[lang(sized)]
+            trait Sized { }
+        }
+
+        goal {
+            u32: Sized
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            WellFormed([[u32]])
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
@@ -407,7 +407,9 @@ fn solve_aggregated(
     expected.assert_eq(head);
 }
 
+mod adts;
 mod arrays;
+mod assoc_consts;
 mod auto_traits;
 mod closures;
 mod coherence_goals;
@@ -415,6 +417,7 @@ mod coinduction;
 mod constants;
 mod cycle;
 mod discriminant_kind;
+mod error_type;
 mod existential_types;
 mod fn_def;
 mod foreign_types;
@@ -430,11 +433,15 @@ mod numerics;
 mod object_safe;
 mod opaque_types;
 mod projection;
+mod raw_pointers;
 mod refs;
 mod scalars;
 mod slices;
+#[cfg(feature = "specialization")]
+mod specialization;
 mod string;
 mod subtype;
+mod trait_aliases;
 mod tuples;
 mod type_flags;
 mod unify;
@@ -354,3 +354,179 @@ fn negative_reorder() {
         }
     }
 }
+
+#[test]
+fn negative_where_clause_in_environment() {
+    test! {
+        program {
+            #[auto] trait Send { }
+            trait Foo { }
+
+            impl<T> Foo for T { }
+        }
+
+        goal {
+            forall<T> { T: Foo }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { if (T: !Foo) { T: Foo } }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Send) { T: Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { if (T: !Send) { T: Send } }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: !Send) { T: !Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { if (T: !Send) { not { T: Send } } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn negative_impls_prove_negative_facts() {
+    test! {
+        program {
+            #[auto] trait Send { }
+
+            struct Foo { }
+            struct Bar<T> { }
+
+            impl !Send for Foo { }
+            impl<T> !Send for Bar<T> where T: Send { }
+        }
+
+        goal {
+            Foo: !Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Bar<Foo>: !Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Send) { Bar<T>: !Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn negative_where_clause_on_items() {
+    test! {
+        program {
+            #[auto] trait Send { }
+            trait Foo { }
+
+            struct S { }
+            struct Wrapper<T> { }
+
+            impl !Send for S { }
+            impl<T> Foo for Wrapper<T> where T: !Send { }
+
+            fn requires_not_send<T>() where T: !Send;
+        }
+
+        goal {
+            Wrapper<S>: Foo
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Wrapper<u32>: Foo
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: !Send) { Wrapper<T>: Foo } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            WellFormed(requires_not_send<S>)
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            WellFormed(requires_not_send<u32>)
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (FromEnv(requires_not_send<T>)) { T: Send } }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (FromEnv(requires_not_send<T>)) { T: !Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn negative_facts_with_binders_in_environment() {
+    test! {
+        program {
+            #[auto] trait Send { }
+            struct Vec<T> { }
+        }
+
+        goal {
+            forall<T> { if (forall<U> { Vec<U>: !Send }) { Vec<T>: Send } }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            if (forall<U> { Vec<U>: !Send }) { exists<T> { Vec<T>: Send } }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        // `Vec<u32>` not being `Send` says nothing about other `Vec`s.
+        goal {
+            if (Vec<u32>: !Send) { exists<T> { Vec<T>: Send } }
+        } yields {
+            expect![["Unique; for<?U0> { substitution [?0 := ^0.0] }"]]
+        }
+
+        goal {
+            if (Vec<u32>: !Send) { Vec<u32>: Send }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
@@ -25,3 +25,78 @@ fn never_is_sized() {
         }
     }
 }
+
+#[test]
+fn never_is_copy_and_clone() {
+    test! {
+        program {
+            #[lang(copy)]
+            trait Copy { }
+
+            #[lang(clone)]
+            trait Clone { }
+
+            // As with scalars, these impls live in libcore.
+            impl Copy for ! { }
+            impl Clone for ! { }
+        }
+        goal {
+            !: Copy
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            !: Clone
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn never_implements_auto_traits() {
+    test! {
+        program {
+            #[auto]
+            trait Send { }
+        }
+        goal {
+            !: Send
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn never_unsizes_to_dyn() {
+    test! {
+        program {
+            #[lang(unsize)]
+            trait Unsize<T> { }
+
+            #[lang(sized)]
+            trait Sized { }
+
+            #[object_safe]
+            trait Foo { }
+
+            #[object_safe]
+            trait Bar { }
+
+            impl Foo for ! { }
+        }
+        goal {
+            forall<'a> { !: Unsize<dyn Foo + 'a> }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: Never: '!1_0 }]"]]
+        }
+
+        goal {
+            forall<'a> { !: Unsize<dyn Bar + 'a> }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
@@ -13,3 +13,37 @@ fn object_safe_flag() {
         goal { not { ObjectSafe(Bar) } } yields { expect![["Unique"]] }
     }
 }
+
+#[test]
+fn dyn_types_of_object_safe_and_unsafe_traits() {
+    test! {
+        program {
+            #[object_safe]
+            trait Write {}
+
+            // e.g. has a generic method, so the host reports it as not object safe
+            trait Serialize {}
+        }
+
+        goal { ObjectSafe(Write) } yields { expect![["Unique"]] }
+        goal { ObjectSafe(Serialize) } yields { expect![["No possible solution"]] }
+
+        goal {
+            forall<'a> { dyn Write + 'a: Write }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'a> { dyn Write + 'a: Serialize }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<'a> { WellFormed(dyn Write + 'a) }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
@@ -50,6 +50,47 @@ fn opaque_reveal() {
     }
 }
 
+#[test]
+fn opaque_iterator() {
+    test! {
+        program {
+            trait Iterator { type Item; }
+
+            struct Counter { }
+            impl Iterator for Counter { type Item = u32; }
+
+            opaque type Iter: Iterator<Item = u32> = Counter;
+        }
+
+        goal {
+            Iter: Iterator
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            <Iter as Iterator>::Item = u32
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // The hidden type is only visible when revealing.
+        goal {
+            Iter = Counter
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            if (Reveal) {
+                Iter = Counter
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
 #[test]
 fn opaque_where_clause() {
     test! {
@@ -304,6 +304,51 @@ fn projection_equality_from_env() {
     }
 }
 
+#[test]
+fn assoc_type_binding_in_single_bound() {
+    test! {
+        program {
+            trait Iterator {
+                type Item;
+            }
+
+            struct Foo<T> where T: Iterator<Item = u32> { }
+        }
+
+        // `T: Iterator<Item = u32>` lowers to both `T: Iterator` and
+        // `AliasEq(<T as Iterator>::Item = u32)`.
+        goal {
+            forall<T> {
+                if (T: Iterator<Item = u32>) {
+                    T: Iterator
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> {
+                if (T: Iterator<Item = u32>) {
+                    <T as Iterator>::Item = u32
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> {
+                if (FromEnv(Foo<T>)) {
+                    <T as Iterator>::Item = u32
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
 #[test]
 fn projection_equality_nested() {
     test! {
@@ -332,6 +377,59 @@ fn projection_equality_nested() {
     }
 }
 
+#[test]
+fn normalize_nested_projections_through_impls() {
+    test! {
+        program {
+            trait A { type X; }
+            trait B { type Y; }
+
+            struct Foo { }
+            struct Bar { }
+            struct Baz { }
+            struct Vec<T> { }
+
+            impl A for Foo { type X = Bar; }
+            impl B for Bar { type Y = Baz; }
+        }
+
+        // Both layers are normalized by a single `AliasEq` goal
+        goal {
+            exists<U> {
+                <<Foo as A>::X as B>::Y = U
+            }
+        } yields[SolverChoice::slg_default()] {
+            // this is wrong, chalk#234
+            expect![["Ambiguous; no inference guidance"]]
+        } yields[SolverChoice::recursive_default()] {
+            expect![["Unique; substitution [?0 := Baz]"]]
+        }
+
+        goal {
+            exists<U> {
+                Vec<<<Foo as A>::X as B>::Y> = Vec<U>
+            }
+        } yields[SolverChoice::slg_default()] {
+            // this is wrong, chalk#234
+            expect![["Ambiguous; no inference guidance"]]
+        } yields[SolverChoice::recursive_default()] {
+            expect![["Unique; substitution [?0 := Baz]"]]
+        }
+
+        goal {
+            <<Foo as A>::X as B>::Y = Baz
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            <<Foo as A>::X as B>::Y = Bar
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
 #[test]
 fn iterator_flatten() {
     test! {
@@ -414,6 +512,48 @@ fn normalize_gat1() {
     }
 }
 
+#[test]
+fn normalize_gat_lifetime_and_type_params() {
+    test! {
+        program {
+            struct Foo { }
+            struct Ref<'a, T> { }
+
+            trait Trait {
+                type Item<'a, U>;
+            }
+
+            impl Trait for Foo {
+                type Item<'a, U> = Ref<'a, U>;
+            }
+        }
+
+        goal {
+            forall<'x> {
+                exists<V> {
+                    Normalize(<Foo as Trait>::Item<'x, u32> -> V)
+                }
+            }
+        } yields {
+            expect![["Unique; substitution [?0 := Ref<'!1_0, Uint(U32)>]"]]
+        }
+
+        goal {
+            <Foo as Trait>::Item<'static, u32> = Ref<'static, u32>
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'x> {
+                <Foo as Trait>::Item<'x, u32> = Ref<'x, i32>
+            }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
 #[test]
 fn normalize_gat2() {
     test! {
@@ -1107,3 +1247,125 @@ fn projection_to_opaque() {
         }
     }
 }
+
+#[test]
+fn projection_from_assoc_type_default() {
+    test! {
+        program {
+            trait Clone { }
+            trait Iterator { type Item = u32; }
+            struct Foo { }
+            struct Bar { }
+            struct Vec<T> { }
+            impl Clone for Foo { }
+            impl Iterator for Foo { type Item = i64; }
+            impl Iterator for Bar { }
+            impl<T> Iterator for Vec<T> where T: Clone { }
+        }
+
+        // An impl's own value wins over the default.
+        goal {
+            exists<U> { Normalize(<Foo as Iterator>::Item -> U) }
+        } yields {
+            expect![["Unique; substitution [?0 := Int(I64)]"]]
+        }
+
+        goal {
+            exists<U> { Normalize(<Bar as Iterator>::Item -> U) }
+        } yields {
+            expect![["Unique; substitution [?0 := Uint(U32)]"]]
+        }
+
+        goal {
+            <Bar as Iterator>::Item = u32
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // The impl's where clauses still have to hold.
+        goal {
+            exists<U> { Normalize(<Vec<Foo> as Iterator>::Item -> U) }
+        } yields {
+            expect![["Unique; substitution [?0 := Uint(U32)]"]]
+        }
+
+        goal {
+            exists<U> { Normalize(<Vec<Bar> as Iterator>::Item -> U) }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
+#[test]
+fn generic_assoc_type_default() {
+    test! {
+        program {
+            struct Wrapper<T> { }
+            struct Foo { }
+            trait Container<T> { type Elem<U> = Wrapper<(T, U)>; }
+            impl<T> Container<T> for Foo { }
+        }
+
+        goal {
+            exists<X> { Normalize(<Foo as Container<u8>>::Elem<i32> -> X) }
+        } yields {
+            expect![["Unique; substitution [?0 := Wrapper<2<Uint(U8), Int(I32)>>]"]]
+        }
+    }
+}
+
+#[test]
+fn supertrait_assoc_type_from_subtrait_bound() {
+    test! {
+        program {
+            trait Super {
+                type Item;
+            }
+            trait Sub where Self: Super { }
+            trait SubU32 where Self: Super<Item = u32> { }
+        }
+
+        goal {
+            forall<T> {
+                if (T: Sub) {
+                    T: Super
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // The binding in the supertrait bound of `SubU32` is implied by a
+        // `T: SubU32` bound, just as it would be by `T: Super<Item = u32>`.
+        goal {
+            forall<T> {
+                if (T: SubU32) {
+                    <T as Super>::Item = u32
+                }
+            }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> {
+                if (T: SubU32) {
+                    <T as Super>::Item = i32
+                }
+            }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> {
+                if (T: Sub) {
+                    <T as Super>::Item = u32
+                }
+            }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+
+#[test]
+fn raw_pointers_are_well_formed() {
+    test! {
+        goal {
+            forall<T> { WellFormed(*const T) }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { WellFormed(*mut T) }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn raw_pointers_are_sized() {
+    test! {
+        program {
+            #[lang(sized)]
+            trait Sized { }
+        }
+
+        goal {
+            forall<T> { *const T: Sized }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            *mut [u32]: Sized
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn raw_pointers_are_copy_with_libcore_impls() {
+    test! {
+        program {
+            #[lang(copy)]
+            trait Copy { }
+            #[lang(clone)]
+            trait Clone { }
+
+            impl<T> Copy for *const T { }
+            impl<T> Clone for *const T { }
+            impl<T> Copy for *mut T { }
+            impl<T> Clone for *mut T { }
+
+            struct NotCopy { }
+        }
+
+        goal {
+            forall<T> { *const T: Copy }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            *mut NotCopy: Clone
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn raw_pointers_are_not_send_with_libcore_impls() {
+    test! {
+        program {
+            #[auto] trait Send { }
+
+            impl<T> !Send for *const T { }
+            impl<T> !Send for *mut T { }
+
+            struct Wrapper {
+                ptr: *const u32
+            }
+
+            struct SendWrapper {
+                ptr: *mut u32
+            }
+            impl Send for SendWrapper { }
+        }
+
+        goal {
+            forall<T> { *const T: Send }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            *mut u32: Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            Wrapper: Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            SendWrapper: Send
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
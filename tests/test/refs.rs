@@ -67,3 +67,88 @@ fn mut_refs_are_sized() {
         }
     }
 }
+
+#[test]
+fn refs_auto_traits_follow_libcore_impls() {
+    test! {
+        program {
+            #[auto] trait Send { }
+            #[auto] trait Sync { }
+
+            impl<'a, T> Send for &'a T where T: Sync { }
+            impl<'a, T> Send for &'a mut T where T: Send { }
+
+            struct SendOnly { }
+            impl !Sync for SendOnly { }
+        }
+
+        goal {
+            forall<'a> { &'a u32: Send }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'a> { &'a SendOnly: Send }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<'a> { &'a mut SendOnly: Send }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'a, T> { if (T: Sync) { &'a T: Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'a, T> { if (T: Sync) { &'a mut T: Send } }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
+#[test]
+fn shared_refs_are_copy_but_mut_refs_are_not() {
+    test! {
+        program {
+            #[lang(copy)]
+            trait Copy { }
+            #[lang(clone)]
+            trait Clone { }
+
+            impl<'a, T> Copy for &'a T { }
+            impl<'a, T> Clone for &'a T { }
+        }
+
+        goal {
+            forall<'a, T> { &'a T: Copy }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'a, T> { &'a T: Clone }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<'a, T> { &'a mut T: Copy }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<'a, T> { &'a mut T: Clone }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
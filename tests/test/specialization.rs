@@ -0,0 +1,59 @@
+use super::*;
+
+#[test]
+fn specialized_assoc_type_value_is_preferred() {
+    test! {
+        program {
+            trait Trait { type Assoc; }
+            impl<T> Trait for T { type Assoc = u32; }
+            impl Trait for i32 { type Assoc = i64; }
+        }
+
+        goal {
+            i32: Trait
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            exists<U> { Normalize(<i32 as Trait>::Assoc -> U) }
+        } yields {
+            expect![["Unique; substitution [?0 := Int(I64)]"]]
+        }
+
+        goal {
+            exists<U> { Normalize(<u8 as Trait>::Assoc -> U) }
+        } yields {
+            expect![["Unique; substitution [?0 := Uint(U32)]"]]
+        }
+
+        // Either impl could apply, depending on `T`.
+        goal {
+            exists<T, U> { Normalize(<T as Trait>::Assoc -> U) }
+        } yields {
+            expect![["Ambiguous; no inference guidance"]]
+        }
+    }
+}
+
+#[test]
+fn specialized_impl_with_where_clauses_is_not_preferred() {
+    test! {
+        program {
+            trait Clone { }
+            trait Trait { type Assoc; }
+            struct Vec<T> { }
+            impl<T> Trait for T { type Assoc = u32; }
+            impl<T> Trait for Vec<T> where T: Clone { type Assoc = i64; }
+            impl Clone for i32 { }
+        }
+
+        // Whether `impl Trait for Vec<T>` applies depends on `T: Clone`,
+        // which isn't checked when picking the impls.
+        goal {
+            exists<U> { Normalize(<Vec<i32> as Trait>::Assoc -> U) }
+        } yields {
+            expect![["Ambiguous; no inference guidance"]]
+        }
+    }
+}
@@ -27,6 +27,8 @@ fn str_is_not_sized() {
         }
 
         goal { not { str: Sized } } yields { expect![["Unique"]] }
+        goal { not { [str]: Sized } } yields { expect![["Unique"]] }
+        goal { forall<'a> { &'a str: Sized } } yields { expect![["Unique"]] }
     }
 }
 
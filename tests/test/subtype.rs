@@ -49,6 +49,50 @@ fn struct_lifetime_variance() {
     }
 }
 
+/// Variance is supplied by the database; a struct without a
+/// `#[variance]` annotation is invariant, as `Cell<T>` would be.
+#[test]
+fn unannotated_struct_is_invariant() {
+    test! {
+        program {
+            #[variance(Covariant)]
+            struct Box<T> { }
+            struct Cell<T> { }
+        }
+
+        goal {
+            forall<'a, 'b> {
+                Subtype(Box<&'a u32>, Box<&'b u32>)
+            }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: '!1_0: '!1_1 }]"]]
+        }
+
+        goal {
+            forall<'a, 'b> {
+                Subtype(Cell<&'a u32>, Cell<&'b u32>)
+            }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: '!1_0: '!1_1 }, InEnvironment { environment: Env([]), goal: '!1_1: '!1_0 }]"]]
+        }
+    }
+}
+
+/// Test that function pointers are contravariant in their arguments:
+/// `fn(&'a u32) <: fn(&'b u32)` if `'b: 'a`
+#[test]
+fn fn_arg_contravariance() {
+    test! {
+        goal {
+            forall<'a, 'b> {
+                Subtype(fn(&'a u32), fn(&'b u32))
+            }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: '!1_1: '!1_0 }]"]]
+        }
+    }
+}
+
 /// Test that `&'a u32 <: &'b u32` if `'a: 'b`
 #[test]
 fn ref_lifetime_variance() {
@@ -64,6 +108,29 @@ fn ref_lifetime_variance() {
     }
 }
 
+/// Test that `&'a mut T` is invariant in `T`: `&'a mut &'b u32 <: &'a mut &'c u32`
+/// requires `'b == 'c`, while the outer lifetime stays covariant.
+#[test]
+fn mut_ref_invariance() {
+    test! {
+        goal {
+            forall<'a, 'b, 'c> {
+                Subtype(&'a mut &'b u32, &'a mut &'c u32)
+            }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: '!1_1: '!1_2 }, InEnvironment { environment: Env([]), goal: '!1_2: '!1_1 }]"]]
+        }
+
+        goal {
+            forall<'a, 'b, 'c> {
+                Subtype(&'a mut &'c u32, &'b mut &'c u32)
+            }
+        } yields {
+            expect![["Unique; lifetime constraints [InEnvironment { environment: Env([]), goal: '!1_0: '!1_1 }]"]]
+        }
+    }
+}
+
 #[test]
 fn fn_lifetime_variance_args() {
     test! {
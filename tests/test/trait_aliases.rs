@@ -0,0 +1,117 @@
+//! Tests for trait aliases, which lower to a trait whose where clauses are the
+//! aliased bounds and which is implemented for every type that satisfies them.
+
+use super::*;
+
+#[test]
+fn trait_alias_requires_all_bounds() {
+    test! {
+        program {
+            trait Display { }
+            trait Debug { }
+            trait Printable = Display + Debug;
+
+            struct Foo { }
+            struct Bar { }
+            impl Display for Foo { }
+            impl Debug for Foo { }
+            impl Display for Bar { }
+        }
+
+        goal {
+            Foo: Printable
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Bar: Printable
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Display; T: Debug) { T: Printable } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { if (T: Display) { T: Printable } }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
+#[test]
+fn trait_alias_implies_bounds() {
+    test! {
+        program {
+            trait Display { }
+            trait Debug { }
+            trait Printable = Display + Debug;
+        }
+
+        goal {
+            forall<T> { if (T: Printable) { T: Display } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { if (T: Printable) { T: Debug } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn trait_alias_with_parameters_and_assoc_bindings() {
+    test! {
+        program {
+            trait Iterator { type Item; }
+            trait Into<T> { }
+            trait IntoIterOf<T> = Iterator<Item = T> + Into<T>;
+
+            struct Foo { }
+            impl Iterator for Foo { type Item = u32; }
+            impl Into<u32> for Foo { }
+        }
+
+        goal {
+            Foo: IntoIterOf<u32>
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            Foo: IntoIterOf<i32>
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T, U> { if (T: IntoIterOf<U>) { T: Into<U> } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn trait_alias_cannot_be_implemented() {
+    lowering_error! {
+        program {
+            trait Display { }
+            trait Printable = Display;
+
+            struct Foo { }
+            impl Printable for Foo { }
+        }
+        error_msg {
+            "cannot implement trait alias `Printable`"
+        }
+    }
+}
@@ -294,3 +294,105 @@ fn tuples_are_wf() {
         }
     }
 }
+
+#[test]
+fn tuples_implement_auto_traits() {
+    test! {
+        program {
+            #[auto] trait Send { }
+
+            struct Foo { }
+            struct Bar { }
+            impl !Send for Bar { }
+        }
+
+        goal {
+            (): Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            (Foo, u32): Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            (Foo, ((), (u32, Foo))): Send
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            (Foo, Bar): Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            (Foo, ((), (u32, Bar))): Send
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { (T, Foo): Send }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Send) { (T, Foo): Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+    }
+}
+
+#[test]
+fn builtin_tuple_trait() {
+    test! {
+        program {
+            #[lang(tuple_trait)]
+            trait Tuple { }
+            struct A { }
+        }
+
+        goal {
+            (i32, u32): Tuple
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            (): Tuple
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { (T, A, (u8,)): Tuple }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            i32: Tuple
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            A: Tuple
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { T: Tuple }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
@@ -412,6 +412,30 @@ fn array_unsizing() {
         } yields {
             expect![["No possible solution"]]
         }
+
+        goal {
+            [i32; 3]: Unsize<[i32]>
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            [i32; 3]: Unsize<[u32]>
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T, const N> { [T; N]: Unsize<[T]> }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            exists<U> { [i32; 3]: Unsize<[U]> }
+        } yields {
+            expect![["Unique; substitution [?0 := Int(I32)]"]]
+        }
     }
 }
 
@@ -36,6 +36,43 @@ fn struct_wf() {
     }
 }
 
+#[test]
+fn generic_struct_wf_requires_where_clauses() {
+    test! {
+        program {
+            trait Clone { }
+            #[auto] trait Send { }
+            struct Foo<T> where T: Clone { t: T }
+        }
+
+        goal {
+            forall<T> { if (T: Clone) { WellFormed(Foo<T>) } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { WellFormed(Foo<T>) }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        // The automatic `Send` impl only looks at the fields; it's up to the
+        // well-formedness of `Foo<T>` to require `T: Clone`.
+        goal {
+            forall<T> { if (T: Send) { Foo<T>: Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { if (T: Send) { WellFormed(Foo<T>) } }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
 #[test]
 fn enum_wf() {
     test! {
@@ -111,10 +148,75 @@ fn drop_compatible() {
 #[test]
 fn placeholder_wf() {
     test! {
+        program {
+            #[auto] trait Send { }
+            trait Eq { }
+            struct Foo<T> where T: Eq { }
+        }
+
         goal {
             forall<T> { WellFormed(T) }
         } yields {
             expect![["Unique"]]
         }
+
+        goal {
+            forall<T> { if (FromEnv(T)) { WellFormed(T) } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        // Facts about placeholders come from the environment
+        goal {
+            forall<T> { if (T: Send) { T: Send } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { if (T: Eq) { WellFormed(Foo<T>) } }
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { WellFormed(Foo<T>) }
+        } yields {
+            expect![["No possible solution"]]
+        }
+    }
+}
+
+#[test]
+fn recursive_struct_wf() {
+    test! {
+        program {
+            trait Eq { }
+            struct Box<T> { }
+            struct List<T> where T: Eq {
+                next: Box<List<T>>
+            }
+
+            struct Baz { }
+            impl Eq for Baz { }
+        }
+
+        goal {
+            WellFormed(List<Baz>)
+        } yields {
+            expect![["Unique"]]
+        }
+
+        goal {
+            forall<T> { WellFormed(List<T>) }
+        } yields {
+            expect![["No possible solution"]]
+        }
+
+        goal {
+            forall<T> { if (T: Eq) { WellFormed(List<T>) } }
+        } yields {
+            expect![["Unique"]]
+        }
     }
 }
@@ -149,6 +149,32 @@ fn ill_formed_ty_decl() {
     }
 }
 
+#[test]
+fn recursive_ty_decl() {
+    lowering_success! {
+        program {
+            struct Box<T> { }
+
+            struct List<T> {
+                next: Box<List<T>>
+            }
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Eq { }
+            struct Box<T> where T: Eq { }
+
+            struct List<T> {
+                next: Box<List<T>>
+            }
+        } error_msg {
+            "type declaration `List` does not meet well-formedness requirements"
+        }
+    }
+}
+
 #[test]
 fn implied_bounds_on_ty_decl() {
     lowering_success! {
@@ -234,6 +260,49 @@ fn ill_formed_type_in_header() {
     }
 }
 
+#[test]
+fn applied_types_in_impl_header() {
+    // Input types of the impl header are assumed to be well-formed (their
+    // requirements are implied bounds), so no `T: Eq` is needed here.
+    lowering_success! {
+        program {
+            trait Eq { }
+            trait Bar { }
+
+            struct Foo<T> where T: Eq { }
+
+            impl<T> Bar for Foo<T> { }
+        }
+    }
+
+    // The same type in a where clause must be proven well-formed.
+    lowering_error! {
+        program {
+            trait Eq { }
+            trait Bar { }
+            trait Baz { }
+
+            struct Foo<T> where T: Eq { }
+
+            impl<T> Bar for T where Foo<T>: Baz { }
+        } error_msg {
+            "trait impl for `Bar` does not meet well-formedness requirements"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Bar { }
+
+            struct Foo<T> { }
+
+            impl<T> Bar for Foo<T, T> { }
+        } error_msg {
+            "`Foo` takes 1 type parameters, not 2"
+        }
+    }
+}
+
 #[test]
 fn bound_in_header_from_env() {
     lowering_success! {
@@ -274,6 +343,45 @@ fn bound_in_header_from_env() {
     }
 }
 
+#[test]
+fn assoc_type_value_must_meet_bounds() {
+    lowering_success! {
+        program {
+            trait Clone { }
+            trait Iterator {
+                type Item: Clone;
+            }
+
+            struct Counter { }
+            struct Count { }
+            impl Clone for Count { }
+
+            impl Iterator for Counter {
+                type Item = Count;
+            }
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Clone { }
+            trait Iterator {
+                type Item: Clone;
+            }
+
+            struct Counter { }
+            struct Count { }
+
+            impl Iterator for Counter {
+                // `Count` is not `Clone`.
+                type Item = Count;
+            }
+        } error_msg {
+            "trait impl for `Iterator` does not meet well-formedness requirements"
+        }
+    }
+}
+
 #[test]
 fn mixed_indices_check_projection_bounds() {
     lowering_success! {
@@ -1088,6 +1196,18 @@ fn drop_constraints() {
            "trait impl for `Drop` does not meet well-formedness requirements"
         }
     }
+
+    // Drop can only be implemented for ADTs
+    lowering_error! {
+        program {
+            #[lang(drop)]
+            trait Drop { }
+
+            impl Drop for u32 { }
+        } error_msg {
+           "trait impl for `Drop` does not meet well-formedness requirements"
+        }
+    }
 }
 
 #[test]
@@ -1438,3 +1558,34 @@ fn coerce_unsized_struct() {
         }
     }
 }
+
+#[test]
+fn negative_where_clause_in_impl_environment() {
+    lowering_success! {
+        program {
+            #[auto] trait Send { }
+            trait NotSend { }
+            trait Foo { type Assoc: NotSend; }
+
+            struct Wrapper<T> { }
+
+            impl<T> NotSend for T where T: !Send { }
+            impl<T> Foo for Wrapper<T> where T: !Send { type Assoc = T; }
+        }
+    }
+
+    lowering_error! {
+        program {
+            #[auto] trait Send { }
+            trait NotSend { }
+            trait Foo { type Assoc: NotSend; }
+
+            struct Wrapper<T> { }
+
+            impl<T> NotSend for T where T: !Send { }
+            impl<T> Foo for Wrapper<T> { type Assoc = T; }
+        } error_msg {
+            "trait impl for `Foo` does not meet well-formedness requirements"
+        }
+    }
+}